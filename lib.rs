@@ -23,7 +23,7 @@
 //! `Option<PositiveIsize>` is particularly useful as a space-efficient optional
 //! `Vec` index, since Rust's `Vec` structure is
 //! [limited](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.push) to
-//! `isize::MAX` entries.
+//! `isize::MAX` entries. [`OptionalIndex`] wraps this pattern with an index-oriented API.
 //!
 //! [`NonZero`]: (https://doc.rust-lang.org/std/num/struct.NonZero.html)
 
@@ -31,7 +31,7 @@
 #![no_std]
 
 macro_rules! impl_positive {
-    ($(#[$attr:meta])* $ty:ident, $sty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
+    ($ty:ident, $sty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
 
         #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
         ///
@@ -59,52 +59,31 @@ macro_rules! impl_positive {
         ///
         /// This enables some memory layout optimization.
         #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($base), "`].")]
-        #[derive(Copy, Clone)]
-        #[repr(C)]
-        $(#[$attr])*
-        pub struct $ty {
-            #[cfg(target_endian = "big")]
-            _hi: PositiveHighByte,
-            _buf: [u8; size_of::<$base>() - 1],
-            #[cfg(target_endian = "little")]
-            _hi: PositiveHighByte,
+        pub type $ty = Positive<$base>;
+
+        impl private::Sealed for $base {
+            type Buf = [u8; size_of::<$base>() - 1];
+            const ZERO: Self = 0;
+            const NEG_ONE: Self = -1;
+            const MIN: Self = <$base>::MIN;
+            const MAX: Self = <$base>::MAX;
         }
 
+        impl SignBoundInt for $base {}
+
         impl $ty {
-            /// The size of this positive integer type in bits.
-            ///
-            #[doc = concat!("This value is equal to [`", stringify!($base), "::BITS`].")]
-            pub const BITS: u32 = <$base>::BITS;
-            /// The smallest value that can be represented by this positive integer type, 0.
-            pub const MIN: Self = unsafe { $ty::new_unchecked(0) };
-            #[doc = concat!("The largest value that can be represented by this positive integer type, equal to [`", stringify!($base), "::MAX`].")]
-            pub const MAX: Self = unsafe { $ty::new_unchecked(<$base>::MAX) };
-            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is positive.")]
-            pub const fn new(value: $base) -> Option<Self> {
-                if value < 0 {
-                    return None;
-                }
-                unsafe { Some(core::mem::transmute::<$base, Self>(value)) }
-            }
-            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is positive.")]
-            /// This results in undefined behaviour if the value is negative.
-            ///
-            /// # Safety
+            /// Returns a number representing the sign of `self`.
             ///
-            /// The value must not be negative.
+            /// - `0` if the number is zero
+            /// - `1` if the number is positive
             #[inline]
-            pub const unsafe fn new_unchecked(value: $base) -> Self {
-                debug_assert!(value >= 0);
-                core::mem::transmute::<$base, Self>(value)
+            pub const fn signum(self) -> $base {
+                (self.get() != 0) as $base
             }
-            /// Returns the contained value as a primitive type.
+            /// Returns the sign of `self`, which is always [`Sign::NonNegative`].
             #[inline]
-            pub const fn get(self) -> $base {
-                unsafe {
-                    let n = core::mem::transmute::<Self, $base>(self);
-                    core::hint::assert_unchecked(n >= 0);
-                    n
-                }
+            pub const fn sign(self) -> Sign {
+                Sign::NonNegative
             }
             /// Returns the number of zeros in the binary representation of `self`.
             #[inline]
@@ -164,12 +143,28 @@ macro_rules! impl_positive {
                     None => None,
                 }
             }
+            /// Checked addition with a signed integer. Computes `self + rhs`, returning [`None`] if
+            /// the result would overflow or be negative.
+            #[inline]
+            pub const fn checked_add_signed(self, rhs: $base) -> Option<Self> {
+                match self.get().checked_add(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
             /// Checked subtraction. Subtracts a positive integer from another positive integer.
             /// Returns [`None`] if the result would overflow into a negative integer.
             #[inline]
             pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
                 Self::new(self.get() - rhs.get())
             }
+            /// Signed difference. Computes `self - rhs` as a signed integer.
+            ///
+            /// The difference of two positive integers always fits, so this can never overflow.
+            #[inline]
+            pub const fn signed_diff(self, rhs: Self) -> $base {
+                self.get() - rhs.get()
+            }
             /// Checked multiplication.
             /// Multiplies a positive integer by another positive integer, returning a positive result.
             /// Returns [`None`] if the result would overflow.
@@ -254,6 +249,121 @@ macro_rules! impl_positive {
             pub const fn checked_ilog10(self) -> Option<u32> {
                 self.get().checked_ilog10()
             }
+            /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+            ///
+            /// Returns `None` if the number is zero, or if the base is less than 2.
+            #[inline]
+            pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+                self.get().checked_ilog(base.get())
+            }
+            /// Returns the integer square root of the number, rounded down.
+            #[inline]
+            pub const fn isqrt(self) -> Self {
+                let n = self.get() as $uns;
+                if n < 2 {
+                    return self;
+                }
+                let mut x = 1 << ((n.ilog2() >> 1) + 1);
+                loop {
+                    let y = (x + n / x) >> 1;
+                    if y >= x {
+                        break;
+                    }
+                    x = y;
+                }
+                unsafe { Self::new_unchecked(x as $base) }
+            }
+            /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive
+            /// infinity.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            #[inline]
+            #[track_caller]
+            pub const fn div_ceil(self, rhs: Self) -> Self {
+                let n = (self.get() as $uns).div_ceil(rhs.get() as $uns);
+                unsafe { Self::new_unchecked(n as $base) }
+            }
+            /// Calculates the smallest value greater than or equal to `self` that is a multiple of
+            /// `rhs`. Returns [`None`] if `rhs` is zero or the operation would result in overflow.
+            #[inline]
+            pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+                match (self.get() as $uns).checked_next_multiple_of(rhs.get() as $uns) {
+                    Some(n) if n <= <$base>::MAX as $uns => unsafe { Some(Self::new_unchecked(n as $base)) },
+                    _ => None,
+                }
+            }
+            /// Returns `true` if `self` is an integer multiple of `rhs`, and false otherwise.
+            ///
+            /// If `rhs` is zero, this returns `true` only if `self` is also zero.
+            #[inline]
+            pub const fn is_multiple_of(self, rhs: Self) -> bool {
+                match rhs.get() {
+                    0 => self.get() == 0,
+                    rhs => self.get() % rhs == 0,
+                }
+            }
+            /// Calculates the middle point of `self` and `rhs`, rounding down.
+            ///
+            /// This can never overflow.
+            #[inline]
+            pub const fn midpoint(self, rhs: Self) -> Self {
+                let n = (self.get() as $uns + rhs.get() as $uns) >> 1;
+                unsafe { Self::new_unchecked(n as $base) }
+            }
+            /// Computes the absolute difference between `self` and `rhs`.
+            ///
+            /// This can never overflow.
+            #[inline]
+            pub const fn abs_diff(self, rhs: Self) -> Self {
+                let n = self.get().abs_diff(rhs.get());
+                unsafe { Self::new_unchecked(n as $base) }
+            }
+            /// Computes the greatest common divisor of `self` and `rhs`.
+            ///
+            /// The result is zero only if both values are zero.
+            #[inline]
+            pub const fn gcd(self, rhs: Self) -> Self {
+                let mut a = self.get();
+                let mut b = rhs.get();
+                while b != 0 {
+                    let r = a % b;
+                    a = b;
+                    b = r;
+                }
+                unsafe { Self::new_unchecked(a) }
+            }
+            /// Computes the least common multiple of `self` and `rhs`, returning [`None`] if the
+            /// result would overflow.
+            ///
+            /// The result is zero if either value is zero.
+            #[inline]
+            pub const fn checked_lcm(self, rhs: Self) -> Option<Self> {
+                let gcd = self.gcd(rhs).get();
+                if gcd == 0 {
+                    return Some(Self::MIN);
+                }
+                match (self.get() / gcd).checked_mul(rhs.get()) {
+                    Some(n) => unsafe { Some(Self::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Computes the least common multiple of `self` and `rhs`.
+            ///
+            /// The result is zero if either value is zero.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if the result would overflow.
+            #[inline]
+            #[track_caller]
+            pub const fn lcm(self, rhs: Self) -> Self {
+                match self.checked_lcm(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to multiply with overflow in ", stringify!($ty))),
+                }
+            }
             /// Saturating addition. Adds a positive integer to another positive integer.
             #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
             #[inline]
@@ -286,36 +396,305 @@ macro_rules! impl_positive {
                 let n = self.get().saturating_pow(rhs);
                 unsafe { Self::new_unchecked(n) }
             }
-        }
-
-        impl Default for $ty {
+            /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the
+            #[doc = concat!("boundary of the positive range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
             #[inline]
-            fn default() -> Self {
-                Self::MIN
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                let n = self.get().wrapping_add(rhs.get());
+                unsafe { Self::new_unchecked(n & <$base>::MAX) }
             }
-        }
-
-        impl PartialEq for $ty {
+            /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the
+            #[doc = concat!("boundary of the positive range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
             #[inline]
-            fn eq(&self, rhs: &Self) -> bool {
-                self.get().eq(&rhs.get())
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                let n = self.get().wrapping_sub(rhs.get());
+                unsafe { Self::new_unchecked(n & <$base>::MAX) }
             }
-        }
-
-        impl PartialOrd for $ty {
-            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
-                Some(self.cmp(rhs))
+            /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the
+            #[doc = concat!("boundary of the positive range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                let n = self.get().wrapping_mul(rhs.get());
+                unsafe { Self::new_unchecked(n & <$base>::MAX) }
             }
-        }
-
-        impl Ord for $ty {
-            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
-                self.get().cmp(&rhs.get())
+            /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of
+            #[doc = concat!("the positive range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                let n = self.get().wrapping_neg();
+                unsafe { Self::new_unchecked(n & <$base>::MAX) }
+            }
+            /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at
+            #[doc = concat!("the boundary of the positive range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_pow(self, exp: u32) -> Self {
+                let n = self.get().wrapping_pow(exp);
+                unsafe { Self::new_unchecked(n & <$base>::MAX) }
+            }
+            /// Overflowing addition. Computes `self + rhs`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the positive range.
+            ///
+            /// See [`wrapping_add`](Self::wrapping_add).
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (n, overflowed) = self.get().overflowing_add(rhs.get());
+                (self.wrapping_add(rhs), overflowed || n < 0)
+            }
+            /// Overflowing subtraction. Computes `self - rhs`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the positive range.
+            ///
+            /// See [`wrapping_sub`](Self::wrapping_sub).
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (n, overflowed) = self.get().overflowing_sub(rhs.get());
+                (self.wrapping_sub(rhs), overflowed || n < 0)
+            }
+            /// Overflowing multiplication. Computes `self * rhs`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the positive range.
+            ///
+            /// See [`wrapping_mul`](Self::wrapping_mul).
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let (n, overflowed) = self.get().overflowing_mul(rhs.get());
+                (self.wrapping_mul(rhs), overflowed || n < 0)
+            }
+            /// Overflowing exponentiation. Computes `self.pow(exp)`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the positive range.
+            ///
+            /// See [`wrapping_pow`](Self::wrapping_pow).
+            #[inline]
+            pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                let (n, overflowed) = self.get().overflowing_pow(exp);
+                (self.wrapping_pow(exp), overflowed || n < 0)
+            }
+            /// Strict addition. Computes `self + rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_add`](Self::checked_add) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to add with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict subtraction. Computes `self - rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_sub`](Self::checked_sub) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to subtract with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict multiplication. Computes `self * rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_mul`](Self::checked_mul) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_mul(self, rhs: Self) -> Self {
+                match self.checked_mul(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to multiply with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict division. Computes `self / rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_div`](Self::checked_div) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_div(self, rhs: Self) -> Self {
+                match self.checked_div(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to divide by zero in ", stringify!($ty))),
+                }
+            }
+            /// Strict exponentiation. Computes `self.pow(exp)`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_pow`](Self::checked_pow) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_pow(self, exp: u32) -> Self {
+                match self.checked_pow(exp) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to multiply with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Unchecked addition. Computes `self + rhs`, assuming the result is representable.
+            ///
+            /// # Safety
+            ///
+            /// This results in undefined behaviour if [`checked_add`](Self::checked_add) would
+            /// return [`None`].
+            #[inline]
+            pub const unsafe fn unchecked_add(self, rhs: Self) -> Self {
+                let n = self.get().unchecked_add(rhs.get());
+                core::hint::assert_unchecked(n >= 0);
+                Self::new_unchecked(n)
+            }
+            /// Unchecked subtraction. Computes `self - rhs`, assuming the result is representable.
+            ///
+            /// # Safety
+            ///
+            /// This results in undefined behaviour if [`checked_sub`](Self::checked_sub) would
+            /// return [`None`].
+            #[inline]
+            pub const unsafe fn unchecked_sub(self, rhs: Self) -> Self {
+                let n = self.get().unchecked_sub(rhs.get());
+                core::hint::assert_unchecked(n >= 0);
+                Self::new_unchecked(n)
+            }
+            /// Unchecked multiplication. Computes `self * rhs`, assuming the result is representable.
+            ///
+            /// # Safety
+            ///
+            /// This results in undefined behaviour if [`checked_mul`](Self::checked_mul) would
+            /// return [`None`].
+            #[inline]
+            pub const unsafe fn unchecked_mul(self, rhs: Self) -> Self {
+                let n = self.get().unchecked_mul(rhs.get());
+                core::hint::assert_unchecked(n >= 0);
+                Self::new_unchecked(n)
+            }
+            /// Creates a value from an `i128`, clamping it to the positive range.
+            #[inline]
+            pub const fn clamp_from_i128(value: i128) -> Self {
+                let n = if value < 0 {
+                    0
+                } else if value > <$base>::MAX as i128 {
+                    <$base>::MAX
+                } else {
+                    value as $base
+                };
+                unsafe { Self::new_unchecked(n) }
+            }
+            /// Creates a value from a `u128`, clamping it to the positive range.
+            #[inline]
+            pub const fn clamp_from_u128(value: u128) -> Self {
+                let n = if value > <$base>::MAX as u128 {
+                    <$base>::MAX
+                } else {
+                    value as $base
+                };
+                unsafe { Self::new_unchecked(n) }
+            }
+            impl_clamp_from! { "positive" =>
+                clamp_from_i64(i64), clamp_from_i32(i32), clamp_from_i16(i16), clamp_from_i8(i8),
+                clamp_from_isize(isize); clamp_from_u64(u64), clamp_from_u32(u32),
+                clamp_from_u16(u16), clamp_from_u8(u8), clamp_from_usize(usize)
+            }
+            /// Creates a value from any primitive integer, clamping it to the positive range.
+            ///
+            /// This is not `const`, since it is generic over [`PrimInt`]. The `clamp_from_*`
+            /// constructors for each primitive type can be used in `const` contexts instead.
+            #[inline]
+            pub fn saturating_from<T: PrimInt>(value: T) -> Self {
+                match private::PrimSealed::widen(value) {
+                    private::Wide::Signed(n) => Self::clamp_from_i128(n),
+                    private::Wide::Unsigned(n) => Self::clamp_from_u128(n),
+                }
+            }
+            /// Returns the memory representation of this integer as a byte array in big-endian
+            /// (network) byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_be_bytes()
+            }
+            /// Returns the memory representation of this integer as a byte array in little-endian
+            /// byte order.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_le_bytes()
+            }
+            /// Returns the memory representation of this integer as a byte array in native byte
+            /// order.
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_ne_bytes()
+            }
+            /// Creates an integer from its representation as a byte array in big-endian byte order,
+            /// returning [`None`] if the value is not positive.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Option<Self> {
+                if bytes[0] >= 0x80 {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(<$base>::from_be_bytes(bytes))) }
+            }
+            /// Creates an integer from its representation as a byte array in little-endian byte
+            /// order, returning [`None`] if the value is not positive.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Option<Self> {
+                if bytes[bytes.len() - 1] >= 0x80 {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(<$base>::from_le_bytes(bytes))) }
+            }
+            /// Creates an integer from its memory representation as a byte array in native byte
+            /// order, returning [`None`] if the value is not positive.
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Option<Self> {
+                if cfg!(target_endian = "little") {
+                    Self::from_le_bytes(bytes)
+                } else {
+                    Self::from_be_bytes(bytes)
+                }
+            }
+            /// Checked shift left. Computes `self << rhs`, returning [`None`] if any set bit would
+            /// reach the sign position or be shifted out.
+            #[inline]
+            pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+                if rhs >= self.leading_zeros() {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(self.get() << rhs)) }
+            }
+            /// Saturating shift left. Computes `self << rhs`, saturating at
+            #[doc = concat!("[`", stringify!($ty), "::MAX`]")]
+            /// if any set bit would reach the sign position or be shifted out.
+            #[inline]
+            pub const fn saturating_shl(self, rhs: u32) -> Self {
+                match self.checked_shl(rhs) {
+                    Some(n) => n,
+                    None if self.get() == 0 => self,
+                    None => Self::MAX,
+                }
+            }
+            /// Shifts the value bits to the left by `n`, wrapping the truncated bits to the end of
+            /// the resulting integer.
+            ///
+            /// Only the `BITS - 1` value bits are rotated, so the sign bit is always preserved.
+            #[inline]
+            pub const fn rotate_left_magnitude(self, n: u32) -> Self {
+                const M: u32 = <$base>::BITS - 1;
+                let v = self.get() as $uns;
+                let n = n % M;
+                let r = ((v << n) | (v >> (M - n))) & (<$base>::MAX as $uns);
+                unsafe { Self::new_unchecked(r as $base) }
+            }
+            /// Shifts the value bits to the right by `n`, wrapping the truncated bits to the
+            /// beginning of the resulting integer.
+            ///
+            /// Only the `BITS - 1` value bits are rotated, so the sign bit is always preserved.
+            #[inline]
+            pub const fn rotate_right_magnitude(self, n: u32) -> Self {
+                const M: u32 = <$base>::BITS - 1;
+                self.rotate_left_magnitude(M - n % M)
             }
         }
 
-        impl Eq for $ty {}
-
         impl core::str::FromStr for $ty {
             type Err = core::num::IntErrorKind;
             #[inline]
@@ -325,13 +704,6 @@ macro_rules! impl_positive {
             }
         }
 
-        impl core::hash::Hash for $ty {
-            #[inline]
-            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-                self.get().hash(state);
-            }
-        }
-
         impl core::ops::Div for $ty {
             type Output = Self;
             fn div(self, rhs: Self) -> Self::Output {
@@ -380,6 +752,56 @@ macro_rules! impl_positive {
             }
         }
 
+        impl_arith_op! { Add::add, AddAssign::add_assign, checked_add, wrapping_add, "attempt to add with overflow" for $ty }
+        impl_arith_op! { Sub::sub, SubAssign::sub_assign, checked_sub, wrapping_sub, "attempt to subtract with overflow" for $ty }
+        impl_arith_op! { Mul::mul, MulAssign::mul_assign, checked_mul, wrapping_mul, "attempt to multiply with overflow" for $ty }
+
+        impl core::ops::Shr<u32> for $ty {
+            type Output = Self;
+            #[inline]
+            #[track_caller]
+            fn shr(self, rhs: u32) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() >> rhs) }
+            }
+        }
+        impl core::ops::ShrAssign<u32> for $ty {
+            #[inline]
+            #[track_caller]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = core::ops::Shr::shr(*self, rhs);
+            }
+        }
+
+        impl core::iter::Sum for $ty {
+            #[inline]
+            #[track_caller]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::MIN, core::ops::Add::add)
+            }
+        }
+        impl<'a> core::iter::Sum<&'a $ty> for $ty {
+            #[inline]
+            #[track_caller]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl core::iter::Product for $ty {
+            #[inline]
+            #[track_caller]
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(unsafe { Self::new_unchecked(1) }, core::ops::Mul::mul)
+            }
+        }
+        impl<'a> core::iter::Product<&'a $ty> for $ty {
+            #[inline]
+            #[track_caller]
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+
         impl core::ops::BitAnd<$base> for $ty {
             type Output = Self;
             #[inline]
@@ -405,12 +827,11 @@ macro_rules! impl_positive {
         impl_bit_op! { BitOr::bitor, BitOrAssign::bitor_assign for $ty }
         impl_bit_op! { BitAnd::bitand, BitAndAssign::bitand_assign for $ty }
         impl_bit_op! { BitXor::bitxor, BitXorAssign::bitxor_assign for $ty }
-        impl_fmt! { Display, Debug, Binary, Octal, LowerHex, UpperHex => $ty }
     };
 }
 
 macro_rules! impl_negative {
-    ($(#[$attr:meta])* $ty:ident, $pty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
+    ($ty:ident, $pty:ident, $npty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
 
         #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
         ///
@@ -438,52 +859,20 @@ macro_rules! impl_negative {
         ///
         /// This enables some memory layout optimization.
         #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($base), "`].")]
-        #[derive(Copy, Clone)]
-        $(#[$attr])*
-        #[repr(C)]
-        pub struct $ty {
-            #[cfg(target_endian = "big")]
-            _hi: NegativeHighByte,
-            _buf: [u8; size_of::<$base>() - 1],
-            #[cfg(target_endian = "little")]
-            _hi: NegativeHighByte,
-        }
+        pub type $ty = Negative<$base>;
 
         impl $ty {
-            /// The size of this negative integer type in bits.
+            /// Returns a number representing the sign of `self`.
             ///
-            #[doc = concat!("This value is equal to [`", stringify!($base), "::BITS`].")]
-            pub const BITS: u32 = <$base>::BITS;
-            #[doc = concat!("The smallest value that can be represented by this negative integer type, equal to [`", stringify!($base), "::MIN`].")]
-            pub const MIN: Self = unsafe { $ty::new_unchecked(<$base>::MIN) };
-            /// The largest value that can be represented by this negative integer type, -1.
-            pub const MAX: Self = unsafe { $ty::new_unchecked(-1) };
-            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is negative.")]
-            pub const fn new(value: $base) -> Option<Self> {
-                if value >= 0 {
-                    return None;
-                }
-                unsafe { Some(core::mem::transmute::<$base, Self>(value)) }
-            }
-            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is negative.")]
-            /// This results in undefined behaviour if the value is positive.
-            ///
-            /// # Safety
-            ///
-            /// The value must not be positive.
+            /// Since the value is guaranteed to be negative, this function always returns -1.
             #[inline]
-            pub const unsafe fn new_unchecked(value: $base) -> Self {
-                debug_assert!(value < 0);
-                core::mem::transmute::<$base, Self>(value)
+            pub const fn signum(self) -> $base {
+                -1
             }
-            /// Returns the contained value as a primitive type.
+            /// Returns the sign of `self`, which is always [`Sign::Negative`].
             #[inline]
-            pub const fn get(self) -> $base {
-                unsafe {
-                    let n = core::mem::transmute::<Self, $base>(self);
-                    core::hint::assert_unchecked(n < 0);
-                    n
-                }
+            pub const fn sign(self) -> Sign {
+                Sign::Negative
             }
             /// Returns the number of zeros in the binary representation of `self`.
             #[inline]
@@ -510,6 +899,34 @@ macro_rules! impl_negative {
             pub const fn trailing_zeros(self) -> u32 {
                 self.get().trailing_zeros()
             }
+            /// Returns `true` if and only if `self == -(1 << k)` for some `k`.
+            #[inline]
+            pub const fn is_negated_power_of_two(self) -> bool {
+                self.unsigned_abs().is_power_of_two()
+            }
+            /// Returns the base 2 logarithm of the absolute value of the number, rounded down.
+            ///
+            /// This can never panic, since the absolute value is never zero.
+            #[inline]
+            pub const fn ilog2_magnitude(self) -> u32 {
+                let n = self.unsigned_abs();
+                unsafe { core::hint::assert_unchecked(n != 0) };
+                n.ilog2()
+            }
+            /// Returns the base 10 logarithm of the absolute value of the number, rounded down.
+            ///
+            /// This can never panic, since the absolute value is never zero.
+            #[inline]
+            pub const fn ilog10_magnitude(self) -> u32 {
+                let n = self.unsigned_abs();
+                unsafe { core::hint::assert_unchecked(n != 0) };
+                n.ilog10()
+            }
+            /// Computes the absolute value of `self` without any wrapping or panicking.
+            #[inline]
+            pub const fn unsigned_abs(self) -> $uns {
+                self.get().unsigned_abs()
+            }
             /// Checked absolute value.
             /// Computes `-self`, returning [`None`] if <code>self == [MIN][Self::MIN]</code>.
             #[inline]
@@ -528,6 +945,18 @@ macro_rules! impl_negative {
                     None => None,
                 }
             }
+            /// Checked exponentiation. Computes `self.pow(exp)`, returning [`None`] if overflow
+            /// occurred.
+            ///
+            /// The result is positive if `exp` is even, and negative if `exp` is odd.
+            #[inline]
+            pub const fn checked_pow(self, exp: u32) -> Option<Either<$pty, Self>> {
+                match self.get().checked_pow(exp) {
+                    Some(n) if exp % 2 == 0 => unsafe { Some(Either::Positive($pty::new_unchecked(n))) },
+                    Some(n) => unsafe { Some(Either::Negative(Self::new_unchecked(n))) },
+                    None => None,
+                }
+            }
             /// Checked addition. Adds a negative integer to another negative integer.
             /// Checks for overflow and returns [`None`] on overflow.
             /// As a consequence, the result cannot wrap to positive integers.
@@ -538,12 +967,28 @@ macro_rules! impl_negative {
                     None => None,
                 }
             }
+            /// Checked addition with a signed integer. Computes `self + rhs`, returning [`None`] if
+            /// the result would overflow or not be negative.
+            #[inline]
+            pub const fn checked_add_signed(self, rhs: $base) -> Option<Self> {
+                match self.get().checked_add(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
             /// Checked subtraction. Subtracts a negative integer from another negative integer.
             /// Returns [`None`] if the result would overflow into a positive integer.
             #[inline]
             pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
                 Self::new(self.get() - rhs.get())
             }
+            /// Signed difference. Computes `self - rhs` as a signed integer.
+            ///
+            /// The difference of two negative integers always fits, so this can never overflow.
+            #[inline]
+            pub const fn signed_diff(self, rhs: Self) -> $base {
+                self.get() - rhs.get()
+            }
             /// Checked multiplication.
             /// Multiplies a negative integer by another negative integer, returning a positive result.
             /// Returns [`None`] if the result would overflow.
@@ -611,6 +1056,40 @@ macro_rules! impl_negative {
                     None => None,
                 }
             }
+            /// Checked division by a positive integer. Computes `self / rhs`, rounding towards zero.
+            /// Returns [`None`] if `rhs == 0`.
+            ///
+            /// The quotient may be zero, so the result is returned as a primitive.
+            #[inline]
+            pub const fn checked_div_positive(self, rhs: $pty) -> Option<$base> {
+                self.get().checked_div(rhs.get())
+            }
+            /// Floored division by a positive integer. Computes `self / rhs`, rounding towards
+            /// negative infinity, so the quotient is always negative.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            #[inline]
+            #[track_caller]
+            pub const fn div_floor_positive(self, rhs: $pty) -> Self {
+                let (a, b) = (self.get(), rhs.get());
+                let q = a / b;
+                let n = if a % b != 0 { q - 1 } else { q };
+                unsafe { Self::new_unchecked(n) }
+            }
+            /// Floored remainder by a positive integer. Computes the remainder of
+            /// [`div_floor_positive`](Self::div_floor_positive), which is always in `0..rhs`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            #[inline]
+            #[track_caller]
+            pub const fn rem_positive(self, rhs: $pty) -> $pty {
+                let n = self.get().rem_euclid(rhs.get());
+                unsafe { $pty::new_unchecked(n) }
+            }
             /// Saturating absolute value.
             /// Computes `-self`, returning
             #[doc = concat!("[`", stringify!($pty), "::MAX`]")]
@@ -664,29 +1143,313 @@ macro_rules! impl_negative {
                     None => Self::MAX,
                 }
             }
-        }
-
-        impl PartialEq for $ty {
+            /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the
+            #[doc = concat!("boundary of the negative range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
             #[inline]
-            fn eq(&self, rhs: &Self) -> bool {
-                self.get().eq(&rhs.get())
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                let n = self.get().wrapping_add(rhs.get());
+                unsafe { Self::new_unchecked(n | <$base>::MIN) }
             }
-        }
-
-        impl PartialOrd for $ty {
-            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
-                Some(self.cmp(rhs))
+            /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the
+            #[doc = concat!("boundary of the negative range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                let n = self.get().wrapping_sub(rhs.get());
+                unsafe { Self::new_unchecked(n | <$base>::MIN) }
             }
-        }
-
-        impl Ord for $ty {
-            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
-                self.get().cmp(&rhs.get())
+            /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the
+            #[doc = concat!("boundary of the negative range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                let n = self.get().wrapping_mul(rhs.get());
+                unsafe { Self::new_unchecked(n | <$base>::MIN) }
+            }
+            /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of
+            #[doc = concat!("the negative range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                let n = self.get().wrapping_neg();
+                unsafe { Self::new_unchecked(n | <$base>::MIN) }
+            }
+            /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at
+            #[doc = concat!("the boundary of the negative range, i.e. modulo 2<sup>", stringify!($base), "::BITS - 1</sup>.")]
+            #[inline]
+            pub const fn wrapping_pow(self, exp: u32) -> Self {
+                let n = self.get().wrapping_pow(exp);
+                unsafe { Self::new_unchecked(n | <$base>::MIN) }
+            }
+            /// Overflowing addition. Computes `self + rhs`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the negative range.
+            ///
+            /// See [`wrapping_add`](Self::wrapping_add).
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (n, overflowed) = self.get().overflowing_add(rhs.get());
+                (self.wrapping_add(rhs), overflowed || n >= 0)
+            }
+            /// Overflowing subtraction. Computes `self - rhs`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the negative range.
+            ///
+            /// See [`wrapping_sub`](Self::wrapping_sub).
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (n, overflowed) = self.get().overflowing_sub(rhs.get());
+                (self.wrapping_sub(rhs), overflowed || n >= 0)
+            }
+            /// Overflowing multiplication. Computes `self * rhs`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the negative range.
+            ///
+            /// The product of two negative integers is never negative, so the boolean is always
+            /// `true`. Use [`checked_mul`](Self::checked_mul) to get the positive product.
+            ///
+            /// See [`wrapping_mul`](Self::wrapping_mul).
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                (self.wrapping_mul(rhs), true)
+            }
+            /// Overflowing exponentiation. Computes `self.pow(exp)`, returning the wrapped result along with a
+            /// boolean indicating whether the exact result would have left the negative range.
+            ///
+            /// The result is positive for every even `exp`, including 0, so the boolean is then
+            /// always `true`. Use [`checked_pow`](Self::checked_pow) to get a result tagged by sign.
+            ///
+            /// See [`wrapping_pow`](Self::wrapping_pow).
+            #[inline]
+            pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+                let (_, overflowed) = self.get().overflowing_pow(exp);
+                (self.wrapping_pow(exp), overflowed || exp % 2 == 0)
+            }
+            /// Strict addition. Computes `self + rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_add`](Self::checked_add) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to add with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict subtraction. Computes `self - rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_sub`](Self::checked_sub) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to subtract with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict multiplication. Computes `self * rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_mul`](Self::checked_mul) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_mul(self, rhs: Self) -> $pty {
+                match self.checked_mul(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to multiply with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict division. Computes `self / rhs`, panicking if the result is not representable.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_div`](Self::checked_div) would return [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_div(self, rhs: Self) -> $pty {
+                match self.checked_div(rhs) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to divide with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Strict exponentiation. Computes `self.pow(exp)`, panicking if the result is not
+            /// representable.
+            ///
+            /// The result is positive if `exp` is even, and negative if `exp` is odd.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if [`checked_pow`](Self::checked_pow) would return
+            /// [`None`].
+            #[inline]
+            #[track_caller]
+            pub const fn strict_pow(self, exp: u32) -> Either<$pty, Self> {
+                match self.checked_pow(exp) {
+                    Some(n) => n,
+                    None => panic!(concat!("attempt to multiply with overflow in ", stringify!($ty))),
+                }
+            }
+            /// Unchecked addition. Computes `self + rhs`, assuming the result is representable.
+            ///
+            /// # Safety
+            ///
+            /// This results in undefined behaviour if [`checked_add`](Self::checked_add) would
+            /// return [`None`].
+            #[inline]
+            pub const unsafe fn unchecked_add(self, rhs: Self) -> Self {
+                let n = self.get().unchecked_add(rhs.get());
+                core::hint::assert_unchecked(n < 0);
+                Self::new_unchecked(n)
+            }
+            /// Unchecked subtraction. Computes `self - rhs`, assuming the result is representable.
+            ///
+            /// # Safety
+            ///
+            /// This results in undefined behaviour if [`checked_sub`](Self::checked_sub) would
+            /// return [`None`].
+            #[inline]
+            pub const unsafe fn unchecked_sub(self, rhs: Self) -> Self {
+                let n = self.get().unchecked_sub(rhs.get());
+                core::hint::assert_unchecked(n < 0);
+                Self::new_unchecked(n)
+            }
+            /// Unchecked multiplication. Computes `self * rhs`, assuming the result is representable.
+            ///
+            /// # Safety
+            ///
+            /// This results in undefined behaviour if [`checked_mul`](Self::checked_mul) would
+            /// return [`None`].
+            #[inline]
+            pub const unsafe fn unchecked_mul(self, rhs: Self) -> $pty {
+                let n = self.get().unchecked_mul(rhs.get());
+                core::hint::assert_unchecked(n >= 0);
+                $pty::new_unchecked(n)
+            }
+            /// Creates a value from an `i128`, clamping it to the negative range.
+            #[inline]
+            pub const fn clamp_from_i128(value: i128) -> Self {
+                let n = if value >= 0 {
+                    -1
+                } else if value < <$base>::MIN as i128 {
+                    <$base>::MIN
+                } else {
+                    value as $base
+                };
+                unsafe { Self::new_unchecked(n) }
+            }
+            /// Creates a value from a `u128`, clamping it to the negative range.
+            ///
+            /// Every `u128` is greater than or equal to zero, so this always returns
+            /// [`MAX`](Self::MAX), -1.
+            #[inline]
+            pub const fn clamp_from_u128(_value: u128) -> Self {
+                Self::MAX
+            }
+            impl_clamp_from! { "negative" =>
+                clamp_from_i64(i64), clamp_from_i32(i32), clamp_from_i16(i16), clamp_from_i8(i8),
+                clamp_from_isize(isize); clamp_from_u64(u64), clamp_from_u32(u32),
+                clamp_from_u16(u16), clamp_from_u8(u8), clamp_from_usize(usize)
+            }
+            /// Creates a value from any primitive integer, clamping it to the negative range.
+            ///
+            /// This is not `const`, since it is generic over [`PrimInt`]. The `clamp_from_*`
+            /// constructors for each primitive type can be used in `const` contexts instead.
+            #[inline]
+            pub fn saturating_from<T: PrimInt>(value: T) -> Self {
+                match private::PrimSealed::widen(value) {
+                    private::Wide::Signed(n) => Self::clamp_from_i128(n),
+                    private::Wide::Unsigned(n) => Self::clamp_from_u128(n),
+                }
+            }
+            /// Returns the memory representation of this integer as a byte array in big-endian
+            /// (network) byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_be_bytes()
+            }
+            /// Returns the memory representation of this integer as a byte array in little-endian
+            /// byte order.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_le_bytes()
+            }
+            /// Returns the memory representation of this integer as a byte array in native byte
+            /// order.
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; core::mem::size_of::<$base>()] {
+                self.get().to_ne_bytes()
+            }
+            /// Creates an integer from its representation as a byte array in big-endian byte order,
+            /// returning [`None`] if the value is not negative.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Option<Self> {
+                if bytes[0] < 0x80 {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(<$base>::from_be_bytes(bytes))) }
+            }
+            /// Creates an integer from its representation as a byte array in little-endian byte
+            /// order, returning [`None`] if the value is not negative.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Option<Self> {
+                if bytes[bytes.len() - 1] < 0x80 {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(<$base>::from_le_bytes(bytes))) }
+            }
+            /// Creates an integer from its memory representation as a byte array in native byte
+            /// order, returning [`None`] if the value is not negative.
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [u8; core::mem::size_of::<$base>()]) -> Option<Self> {
+                if cfg!(target_endian = "little") {
+                    Self::from_le_bytes(bytes)
+                } else {
+                    Self::from_be_bytes(bytes)
+                }
+            }
+            /// Checked shift left. Computes `self << rhs`, returning [`None`] if the sign bit would
+            /// be lost or any value bit would be shifted out.
+            #[inline]
+            pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+                if rhs >= self.get().leading_ones() {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(self.get() << rhs)) }
+            }
+            /// Saturating shift left. Computes `self << rhs`, saturating at
+            #[doc = concat!("[`", stringify!($ty), "::MIN`]")]
+            /// if the sign bit would be lost or any value bit would be shifted out.
+            #[inline]
+            pub const fn saturating_shl(self, rhs: u32) -> Self {
+                match self.checked_shl(rhs) {
+                    Some(n) => n,
+                    None => Self::MIN,
+                }
+            }
+            /// Shifts the value bits to the left by `n`, wrapping the truncated bits to the end of
+            /// the resulting integer.
+            ///
+            /// Only the `BITS - 1` value bits below the sign bit are rotated, so the sign bit is
+            /// always preserved.
+            #[inline]
+            pub const fn rotate_left_magnitude(self, n: u32) -> Self {
+                const M: u32 = <$base>::BITS - 1;
+                let v = self.get() as $uns & (<$base>::MAX as $uns);
+                let n = n % M;
+                let r = ((v << n) | (v >> (M - n))) & (<$base>::MAX as $uns);
+                unsafe { Self::new_unchecked(r as $base | <$base>::MIN) }
+            }
+            /// Shifts the value bits to the right by `n`, wrapping the truncated bits to the
+            /// beginning of the resulting integer.
+            ///
+            /// Only the `BITS - 1` value bits below the sign bit are rotated, so the sign bit is
+            /// always preserved.
+            #[inline]
+            pub const fn rotate_right_magnitude(self, n: u32) -> Self {
+                const M: u32 = <$base>::BITS - 1;
+                self.rotate_left_magnitude(M - n % M)
             }
         }
 
-        impl Eq for $ty {}
-
         impl core::str::FromStr for $ty {
             type Err = core::num::IntErrorKind;
             #[inline]
@@ -696,13 +1459,6 @@ macro_rules! impl_negative {
             }
         }
 
-        impl core::hash::Hash for $ty {
-            #[inline]
-            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-                self.get().hash(state);
-            }
-        }
-
         impl core::ops::BitOr<$base> for $ty {
             type Output = Self;
             #[inline]
@@ -808,81 +1564,269 @@ macro_rules! impl_negative {
             }
         }
 
-        impl_fmt! { Display, Debug, Binary, Octal, LowerHex, UpperHex => $ty }
-        impl_bit_op! { BitOr::bitor, BitOrAssign::bitor_assign for $ty }
-        impl_bit_op! { BitAnd::bitand, BitAndAssign::bitand_assign for $ty }
-    };
-}
-
-macro_rules! impl_fmt {
-    (=> $ty:ty) => {};
-    ($trait:ident $(, $rest:ident)* => $ty:ty) => {
-        impl core::fmt::$trait for $ty {
+        impl core::ops::Add<$pty> for $ty {
+            type Output = $base;
             #[inline]
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                core::fmt::$trait::fmt(&self.get(), f)
+            fn add(self, rhs: $pty) -> Self::Output {
+                self.get() + rhs.get()
             }
         }
-        impl_fmt! { $($rest),* => $ty }
-    };
-}
-
-macro_rules! impl_from {
-    (=> $ty:ty) => {};
-    ($from:ty $(, $rest:ty)* => $ty:ty) => {
-        impl From<$from> for $ty {
+        impl core::ops::Add<$ty> for $pty {
+            type Output = $base;
             #[inline]
-            fn from(value: $from) -> Self {
-                unsafe { Self::new_unchecked(value as _) }
+            fn add(self, rhs: $ty) -> Self::Output {
+                self.get() + rhs.get()
             }
         }
-        impl_from! { $($rest),* => $ty }
-    };
-}
 
-macro_rules! impl_from_get {
-    ($ty:ty =>) => {};
-    ($ty:ty => $from:ty $(, $rest:ty)*) => {
-        impl From<$ty> for $from {
+        impl core::ops::Shr<u32> for $ty {
+            type Output = Self;
             #[inline]
-            fn from(value: $ty) -> Self {
-                unsafe { Self::new_unchecked(value.get() as _) }
+            #[track_caller]
+            fn shr(self, rhs: u32) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() >> rhs) }
             }
         }
-        impl TryFrom<$from> for $ty {
-            type Error = core::num::TryFromIntError;
+        impl core::ops::ShrAssign<u32> for $ty {
             #[inline]
-            fn try_from(value: $from) -> Result<Self, Self::Error> {
-                let value = <_>::try_from(value.get())?;
-                unsafe { Ok(Self::new_unchecked(value)) }
+            #[track_caller]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = core::ops::Shr::shr(*self, rhs);
             }
         }
-        impl_from_get! { $ty => $($rest),*}
-    };
-}
 
-macro_rules! impl_primitive_from {
-    ($ty:ty =>) => {};
-    ($ty:ty => $from:ty $(, $rest:ty)*) => {
-        impl From<$ty> for $from {
+        /// Sums negative integers.
+        ///
+        /// The sum of an empty iterator would be zero, which is not negative, so this panics if
+        #[doc = concat!("the iterator is empty. Sum into [`", stringify!($npty), "`] instead to get zero.")]
+        impl core::iter::Sum for $ty {
             #[inline]
-            fn from(value: $ty) -> Self {
-                value.get() as _
+            #[track_caller]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                let sum = iter.reduce(|a, b| {
+                    if cfg!(debug_assertions) {
+                        match a.checked_add(b) {
+                            Some(n) => n,
+                            None => panic!("attempt to add with overflow"),
+                        }
+                    } else {
+                        a.wrapping_add(b)
+                    }
+                });
+                match sum {
+                    Some(n) => n,
+                    None => panic!("attempt to sum an empty iterator into a negative value"),
+                }
             }
         }
-        impl_primitive_from! { $ty => $($rest),* }
-    };
-}
-
-macro_rules! impl_try_from {
-    ($ty:ty =>) => {};
-    ($ty:ty => $from:ty $(, $rest:ty)*) => {
-        impl TryFrom<$from> for $ty {
-            type Error = core::num::TryFromIntError;
+        impl<'a> core::iter::Sum<&'a $ty> for $ty {
             #[inline]
-            fn try_from(value: $from) -> Result<Self, Self::Error> {
-                let value = <_>::try_from(value.get())?;
-                unsafe { Ok(Self::new_unchecked(value)) }
+            #[track_caller]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl core::ops::Div for $ty {
+            type Output = $pty;
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: Self) -> Self::Output {
+                match self.checked_div(rhs) {
+                    Some(n) => n,
+                    None => panic!("attempt to divide with overflow"),
+                }
+            }
+        }
+        impl core::ops::Div<$pty> for $ty {
+            type Output = $npty;
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: $pty) -> Self::Output {
+                unsafe { $npty::new_unchecked(self.get() / rhs.get()) }
+            }
+        }
+        impl core::ops::Div<$ty> for $pty {
+            type Output = $npty;
+            #[inline]
+            fn div(self, rhs: $ty) -> Self::Output {
+                unsafe { $npty::new_unchecked(self.get() / rhs.get()) }
+            }
+        }
+
+        impl core::ops::Rem for $ty {
+            type Output = $npty;
+            #[inline]
+            fn rem(self, rhs: Self) -> Self::Output {
+                unsafe { $npty::new_unchecked(self.get().wrapping_rem(rhs.get())) }
+            }
+        }
+
+        impl core::ops::Rem<$ty> for $pty {
+            type Output = Self;
+            #[inline]
+            fn rem(self, rhs: $ty) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() % rhs.get()) }
+            }
+        }
+        impl core::ops::RemAssign<$ty> for $pty {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $ty) {
+                *self = core::ops::Rem::rem(*self, rhs);
+            }
+        }
+
+        impl core::ops::Neg for $ty {
+            type Output = $pty;
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> Self::Output {
+                match self.checked_neg() {
+                    Some(n) => n,
+                    None => panic!("attempt to negate with overflow"),
+                }
+            }
+        }
+        impl core::ops::Neg for $pty {
+            type Output = $ty;
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> Self::Output {
+                match self.checked_neg() {
+                    Some(n) => n,
+                    None => panic!("attempt to negate zero to a negative value"),
+                }
+            }
+        }
+
+        impl core::ops::Mul for $ty {
+            type Output = $pty;
+            #[inline]
+            #[track_caller]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn mul(self, rhs: Self) -> Self::Output {
+                if cfg!(debug_assertions) {
+                    match self.checked_mul(rhs) {
+                        Some(n) => n,
+                        None => panic!("attempt to multiply with overflow"),
+                    }
+                } else {
+                    unsafe { $pty::new_unchecked(self.get().wrapping_mul(rhs.get()) & <$base>::MAX) }
+                }
+            }
+        }
+        /// Sign-preserving multiplication by a positive integer.
+        ///
+        /// Panics if `rhs` is zero, since the product would not be negative.
+        impl core::ops::Mul<$pty> for $ty {
+            type Output = Self;
+            #[inline]
+            #[track_caller]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn mul(self, rhs: $pty) -> Self::Output {
+                if rhs.get() == 0 {
+                    panic!("attempt to multiply a negative value by zero");
+                }
+                if cfg!(debug_assertions) {
+                    match self.checked_mul_positive(rhs) {
+                        Some(n) => n,
+                        None => panic!("attempt to multiply with overflow"),
+                    }
+                } else {
+                    unsafe { Self::new_unchecked(self.get().wrapping_mul(rhs.get()) | <$base>::MIN) }
+                }
+            }
+        }
+        impl core::ops::MulAssign<$pty> for $ty {
+            #[inline]
+            #[track_caller]
+            fn mul_assign(&mut self, rhs: $pty) {
+                *self = core::ops::Mul::mul(*self, rhs);
+            }
+        }
+        impl core::ops::Mul<$ty> for $pty {
+            type Output = $ty;
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: $ty) -> Self::Output {
+                core::ops::Mul::mul(rhs, self)
+            }
+        }
+
+        impl_bit_op! { BitOr::bitor, BitOrAssign::bitor_assign for $ty }
+        impl_bit_op! { BitAnd::bitand, BitAndAssign::bitand_assign for $ty }
+    };
+}
+
+macro_rules! impl_fmt {
+    (=> $ty:ty $(where [$($g:tt)*])?) => {};
+    ($trait:ident $(, $rest:ident)* => $ty:ty $(where [$($g:tt)*])?) => {
+        impl$(<$($g)*>)? core::fmt::$trait for $ty {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::$trait::fmt(&self.get(), f)
+            }
+        }
+        impl_fmt! { $($rest),* => $ty $(where [$($g)*])? }
+    };
+}
+
+macro_rules! impl_from {
+    (=> $ty:ty) => {};
+    ($from:ty $(, $rest:ty)* => $ty:ty) => {
+        impl From<$from> for $ty {
+            #[inline]
+            fn from(value: $from) -> Self {
+                unsafe { Self::new_unchecked(value as _) }
+            }
+        }
+        impl_from! { $($rest),* => $ty }
+    };
+}
+
+macro_rules! impl_from_get {
+    ($ty:ty =>) => {};
+    ($ty:ty => $from:ty $(, $rest:ty)*) => {
+        impl From<$ty> for $from {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                unsafe { Self::new_unchecked(value.get() as _) }
+            }
+        }
+        impl TryFrom<$from> for $ty {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                let value = <_>::try_from(value.get())?;
+                unsafe { Ok(Self::new_unchecked(value)) }
+            }
+        }
+        impl_from_get! { $ty => $($rest),*}
+    };
+}
+
+macro_rules! impl_primitive_from {
+    ($ty:ty =>) => {};
+    ($ty:ty => $from:ty $(, $rest:ty)*) => {
+        impl From<$ty> for $from {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.get() as _
+            }
+        }
+        impl_primitive_from! { $ty => $($rest),* }
+    };
+}
+
+macro_rules! impl_try_from {
+    ($ty:ty =>) => {};
+    ($ty:ty => $from:ty $(, $rest:ty)*) => {
+        impl TryFrom<$from> for $ty {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                let value = <_>::try_from(value.get())?;
+                unsafe { Ok(Self::new_unchecked(value)) }
             }
         }
         impl_try_from! { $ty => $($rest),*}
@@ -951,629 +1895,5588 @@ macro_rules! impl_bit_op {
     };
 }
 
-impl_positive! { #[repr(align(1))] PositiveI8, NegativeI8, $ positive_i8, i8, u8 }
-impl_from_get! { PositiveI8 => PositiveI16, PositiveI32, PositiveI64, PositiveIsize }
-impl_primitive_from! { PositiveI8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
-impl_positive_try_from! { u8, u16, u32, u64, u128, usize => PositiveI8, i8 }
-impl_positive_try_from! { i16, i32, i64, i128, isize => PositiveI8, u8, i8 }
-impl_positive_try_from! { i8 => PositiveI8, u8 }
-impl_negative! { #[repr(align(1))] NegativeI8, PositiveI8, $ negative_i8, i8, u8 }
-impl_from_get! { NegativeI8 => NegativeI16, NegativeI32, NegativeI64, NegativeIsize }
-impl_primitive_from! { NegativeI8 => i8, i16, i32, i64, i128, isize }
-impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI8, u8, i8 }
-
-impl_positive! { #[repr(align(2))] PositiveI16, NegativeI16, $ positive_i16, i16, u16 }
-impl_from! { u8 => PositiveI16 }
-impl_from_get! { PositiveI16 => PositiveI32, PositiveI64, PositiveIsize }
-impl_primitive_from! { PositiveI16 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize }
-impl_primitive_try_from! { PositiveI16 => u8, i8 }
-impl_positive_try_from! { u16, u32, u64, u128, usize => PositiveI16, i16 }
-impl_positive_try_from! { i8, i32, i64, i128, isize => PositiveI16, u16, i16 }
-impl_positive_try_from! { i16 => PositiveI16, u16 }
-impl_negative! { #[repr(align(2))] NegativeI16, PositiveI16, $ negative_i16, i16, u16 }
-impl_from_get! { NegativeI16 => NegativeI32, NegativeI64, NegativeIsize }
-impl_primitive_from! { NegativeI16 => i16, i32, i64, i128, isize }
-impl_primitive_try_from! { NegativeI16 => i8 }
-impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI16, u16, i16 }
+macro_rules! impl_arith_op {
+    ($op:ident :: $opm:ident, $aop:ident :: $aopm:ident, $checked:ident, $wrapping:ident, $msg:literal for $ty:ty) => {
+        /// Panics on overflow when debug assertions are enabled.
+        ///
+        /// Otherwise the result wraps around at the boundary of the positive range, i.e. modulo
+        #[doc = concat!("2<sup>BITS - 1</sup>, so it stays positive. This differs from the primitive types: for example `", stringify!($ty), "::MIN - 1` is")]
+        #[doc = concat!("[`", stringify!($ty), "::MAX`]. See [`", stringify!($wrapping), "`](", stringify!($ty), "::", stringify!($wrapping), ").")]
+        impl core::ops::$op for $ty {
+            type Output = Self;
+            #[inline]
+            #[track_caller]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn $opm(self, rhs: Self) -> Self::Output {
+                if cfg!(debug_assertions) {
+                    match self.$checked(rhs) {
+                        Some(n) => n,
+                        None => panic!($msg),
+                    }
+                } else {
+                    unsafe { Self::new_unchecked(self.get().$wrapping(rhs.get()) & Self::MAX.get()) }
+                }
+            }
+        }
 
-impl_positive! { #[repr(align(4))] PositiveI32, NegativeI32, $ positive_i32, i32, u32 }
-impl_from! { u8, u16 => PositiveI32 }
-impl_from_get! { PositiveI32 => PositiveI64 }
-impl_primitive_from! { PositiveI32 => u32, u64, u128, i32, i64, i128 }
-impl_primitive_try_from! { PositiveI32 => u8, u16, usize, i8, i16, isize }
-impl_positive_try_from! { u32, u64, u128, usize => PositiveI32, i32 }
-impl_positive_try_from! { i8, i16, i64, i128, isize => PositiveI32, u32, i32 }
-impl_positive_try_from! { i32 => PositiveI32, u32 }
-impl_negative! { #[repr(align(4))] NegativeI32, PositiveI32, $ negative_i32, i32, u32 }
-impl_from_get! { NegativeI32 => NegativeI64 }
-impl_primitive_from! { NegativeI32 => i32, i64, i128 }
-impl_primitive_try_from! { NegativeI32 => i8, i16, isize }
-impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI32, u32, i32 }
+        /// Panics on overflow when debug assertions are enabled, and otherwise wraps around at the
+        /// boundary of the positive range.
+        impl core::ops::$aop for $ty {
+            #[inline]
+            #[track_caller]
+            fn $aopm(&mut self, rhs: Self) {
+                *self = core::ops::$op::$opm(*self, rhs);
+            }
+        }
+    };
+}
 
-impl_positive! { #[repr(align(8))] PositiveI64, NegativeI64, $ positive_i64, i64, u64 }
-impl_from! { u8, u16, u32 => PositiveI64 }
-impl_primitive_from! { PositiveI64 => u64, u128, i64, i128 }
-impl_primitive_try_from! { PositiveI64 => u8, u16, u32, usize, i8, i16, i32, isize }
-impl_positive_try_from! { u64, u128, usize => PositiveI64, i64 }
-impl_positive_try_from! { i8, i16, i32, i128, isize => PositiveI64, u64, i64 }
-impl_positive_try_from! { i64 => PositiveI64, u64 }
-impl_negative! { #[repr(align(8))] NegativeI64, PositiveI64, $ negative_i64, i64, u64 }
-impl_primitive_from! { NegativeI64 => i64, i128 }
-impl_primitive_try_from! { NegativeI64 => i8, i16, i32, isize }
-impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI64, u64, i64 }
+macro_rules! impl_clamp_from {
+    ($range:literal => $($ifn:ident($ity:ty)),+; $($ufn:ident($uty:ty)),+) => {
+        $(
+            #[doc = concat!("Creates a value from an `", stringify!($ity), "`, clamping it to the ", $range, " range.")]
+            #[inline]
+            pub const fn $ifn(value: $ity) -> Self {
+                Self::clamp_from_i128(value as i128)
+            }
+        )+
+        $(
+            #[doc = concat!("Creates a value from a `", stringify!($uty), "`, clamping it to the ", $range, " range.")]
+            #[inline]
+            pub const fn $ufn(value: $uty) -> Self {
+                Self::clamp_from_u128(value as u128)
+            }
+        )+
+    };
+}
 
-#[cfg(not(any(
-    target_pointer_width = "16",
-    target_pointer_width = "32",
-    target_pointer_width = "64",
-)))]
-compile_error!("unsupported pointer width");
+macro_rules! impl_widening_mul {
+    ($pty:ident, $nty:ident => $wpty:ident, $wnpty:ident, $wbase:ty) => {
+        impl $pty {
+            #[doc = concat!("Widening multiplication. Computes `self * rhs` as a [`", stringify!($wpty), "`].")]
+            ///
+            /// This can never overflow.
+            #[inline]
+            pub const fn widening_mul(self, rhs: Self) -> $wpty {
+                unsafe { $wpty::new_unchecked(self.get() as $wbase * rhs.get() as $wbase) }
+            }
+            #[doc = concat!("Widening multiplication. Computes `self * rhs` as a [`", stringify!($wnpty), "`].")]
+            ///
+            /// This can never overflow.
+            #[inline]
+            pub const fn widening_mul_negative(self, rhs: $nty) -> $wnpty {
+                unsafe { $wnpty::new_unchecked(self.get() as $wbase * rhs.get() as $wbase) }
+            }
+        }
 
-impl_positive! {
-    #[cfg_attr(target_pointer_width = "16", repr(align(2)))]
-    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-    PositiveIsize, NegativeIsize, $ positive_isize, isize, usize
+        impl $nty {
+            #[doc = concat!("Widening multiplication. Computes `self * rhs` as a [`", stringify!($wpty), "`].")]
+            ///
+            /// This can never overflow.
+            #[inline]
+            pub const fn widening_mul(self, rhs: Self) -> $wpty {
+                unsafe { $wpty::new_unchecked(self.get() as $wbase * rhs.get() as $wbase) }
+            }
+            #[doc = concat!("Widening multiplication. Computes `self * rhs` as a [`", stringify!($wnpty), "`].")]
+            ///
+            /// This can never overflow.
+            #[inline]
+            pub const fn widening_mul_positive(self, rhs: $pty) -> $wnpty {
+                unsafe { $wnpty::new_unchecked(self.get() as $wbase * rhs.get() as $wbase) }
+            }
+        }
+    };
 }
-impl_from! { u8 => PositiveIsize }
-impl_try_from! { PositiveIsize => PositiveI32, PositiveI64 }
-impl_primitive_from! { PositiveIsize => usize, isize }
-impl_primitive_try_from! { PositiveIsize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128 }
-impl_positive_try_from! { u16, u32, u64, u128, usize => PositiveIsize, isize }
-impl_positive_try_from! { i8, i16, i32, i64, i128 => PositiveIsize, usize, isize }
-impl_positive_try_from! { isize => PositiveIsize, usize }
-impl_negative! {
-    #[cfg_attr(target_pointer_width = "16", repr(align(2)))]
-    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-    NegativeIsize, PositiveIsize, $ negative_isize, isize, usize
+
+macro_rules! impl_sign_bound_iterator {
+    ($ty:ident => $wty:ident) => {
+        impl_sign_bound_iterator! { $ty => $wty, wide_sum(iter) {
+            iter.fold($wty::MIN, |acc, n| acc.saturating_add($wty::from(n)))
+        } }
+    };
+    ($ty:ident => $wide:ty, wide_sum($iter:ident) $wide_sum:block) => {
+        impl<I: Iterator<Item = $ty>> private::IteratorSealed<$ty> for I {}
+        impl<I: Iterator<Item = $ty>> SignBoundIterator<$ty> for I {
+            type Wide = $wide;
+            #[inline]
+            fn checked_sum(mut self) -> Option<$ty> {
+                self.try_fold($ty::MIN, $ty::checked_add)
+            }
+            #[inline]
+            fn saturating_sum(self) -> $ty {
+                self.fold($ty::MIN, $ty::saturating_add)
+            }
+            #[inline]
+            fn checked_product(mut self) -> Option<$ty> {
+                self.try_fold(unsafe { $ty::new_unchecked(1) }, $ty::checked_mul)
+            }
+            #[inline]
+            fn wide_sum(self) -> $wide {
+                let $iter = self;
+                $wide_sum
+            }
+        }
+    };
 }
-impl_try_from! { NegativeIsize => NegativeI32, NegativeI64 }
-impl_primitive_from! { NegativeIsize => isize }
-impl_primitive_try_from! { NegativeIsize => i8, i16, i32, i64, i128 }
-impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeIsize, usize, isize }
 
-#[derive(Copy, Clone)]
-#[repr(u8)]
-enum PositiveHighByte {
-    _0 = 0,
-    _1 = 1,
-    _2 = 2,
-    _3 = 3,
-    _4 = 4,
-    _5 = 5,
-    _6 = 6,
-    _7 = 7,
-    _8 = 8,
-    _9 = 9,
-    _10 = 10,
-    _11 = 11,
-    _12 = 12,
-    _13 = 13,
-    _14 = 14,
-    _15 = 15,
-    _16 = 16,
-    _17 = 17,
-    _18 = 18,
-    _19 = 19,
-    _20 = 20,
-    _21 = 21,
-    _22 = 22,
-    _23 = 23,
-    _24 = 24,
-    _25 = 25,
-    _26 = 26,
-    _27 = 27,
-    _28 = 28,
-    _29 = 29,
-    _30 = 30,
-    _31 = 31,
-    _32 = 32,
-    _33 = 33,
-    _34 = 34,
-    _35 = 35,
-    _36 = 36,
-    _37 = 37,
-    _38 = 38,
-    _39 = 39,
-    _40 = 40,
-    _41 = 41,
-    _42 = 42,
-    _43 = 43,
-    _44 = 44,
-    _45 = 45,
-    _46 = 46,
-    _47 = 47,
-    _48 = 48,
-    _49 = 49,
-    _50 = 50,
-    _51 = 51,
-    _52 = 52,
-    _53 = 53,
-    _54 = 54,
-    _55 = 55,
-    _56 = 56,
-    _57 = 57,
-    _58 = 58,
-    _59 = 59,
-    _60 = 60,
-    _61 = 61,
-    _62 = 62,
-    _63 = 63,
-    _64 = 64,
-    _65 = 65,
-    _66 = 66,
-    _67 = 67,
-    _68 = 68,
-    _69 = 69,
-    _70 = 70,
-    _71 = 71,
-    _72 = 72,
-    _73 = 73,
-    _74 = 74,
-    _75 = 75,
-    _76 = 76,
-    _77 = 77,
-    _78 = 78,
-    _79 = 79,
-    _80 = 80,
-    _81 = 81,
-    _82 = 82,
-    _83 = 83,
-    _84 = 84,
-    _85 = 85,
-    _86 = 86,
-    _87 = 87,
-    _88 = 88,
-    _89 = 89,
-    _90 = 90,
-    _91 = 91,
-    _92 = 92,
-    _93 = 93,
-    _94 = 94,
-    _95 = 95,
-    _96 = 96,
-    _97 = 97,
-    _98 = 98,
-    _99 = 99,
-    _100 = 100,
-    _101 = 101,
-    _102 = 102,
-    _103 = 103,
-    _104 = 104,
-    _105 = 105,
-    _106 = 106,
-    _107 = 107,
-    _108 = 108,
-    _109 = 109,
-    _110 = 110,
-    _111 = 111,
-    _112 = 112,
-    _113 = 113,
-    _114 = 114,
-    _115 = 115,
-    _116 = 116,
-    _117 = 117,
-    _118 = 118,
-    _119 = 119,
-    _120 = 120,
-    _121 = 121,
-    _122 = 122,
-    _123 = 123,
-    _124 = 124,
-    _125 = 125,
-    _126 = 126,
-    _127 = 127,
+macro_rules! impl_atomic {
+    ($aty:ident, $ty:ident, $atomic:ident, $base:ty, $width:literal, $sign:literal) => {
+        #[doc = concat!("A [`", stringify!($ty), "`] which can be safely shared between threads.")]
+        ///
+        #[doc = concat!("This type has the same in-memory representation as [`", stringify!($ty), "`].")]
+        #[cfg(target_has_atomic = $width)]
+        #[repr(transparent)]
+        pub struct $aty(core::sync::atomic::$atomic);
+
+        #[cfg(target_has_atomic = $width)]
+        impl $aty {
+            #[doc = concat!("Creates a new `", stringify!($aty), "`.")]
+            #[inline]
+            pub const fn new(value: $ty) -> Self {
+                Self(core::sync::atomic::$atomic::new(value.get()))
+            }
+            /// Consumes the atomic and returns the contained value.
+            #[inline]
+            pub fn into_inner(self) -> $ty {
+                unsafe { $ty::new_unchecked(self.0.into_inner()) }
+            }
+            /// Loads a value from the atomic.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::load`](core::sync::atomic::", stringify!($atomic), "::load).")]
+            #[inline]
+            pub fn load(&self, order: core::sync::atomic::Ordering) -> $ty {
+                unsafe { $ty::new_unchecked(self.0.load(order)) }
+            }
+            /// Stores a value into the atomic.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::store`](core::sync::atomic::", stringify!($atomic), "::store).")]
+            #[inline]
+            pub fn store(&self, value: $ty, order: core::sync::atomic::Ordering) {
+                self.0.store(value.get(), order)
+            }
+            /// Stores a value into the atomic, returning the previous value.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::swap`](core::sync::atomic::", stringify!($atomic), "::swap).")]
+            #[inline]
+            pub fn swap(&self, value: $ty, order: core::sync::atomic::Ordering) -> $ty {
+                unsafe { $ty::new_unchecked(self.0.swap(value.get(), order)) }
+            }
+            /// Stores a value into the atomic if the current value is the same as the `current`
+            /// value.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::compare_exchange`](core::sync::atomic::", stringify!($atomic), "::compare_exchange).")]
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: $ty,
+                new: $ty,
+                success: core::sync::atomic::Ordering,
+                failure: core::sync::atomic::Ordering,
+            ) -> Result<$ty, $ty> {
+                match self.0.compare_exchange(current.get(), new.get(), success, failure) {
+                    Ok(n) => unsafe { Ok($ty::new_unchecked(n)) },
+                    Err(n) => unsafe { Err($ty::new_unchecked(n)) },
+                }
+            }
+            /// Stores a value into the atomic if the current value is the same as the `current`
+            /// value. This function is allowed to spuriously fail.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::compare_exchange_weak`](core::sync::atomic::", stringify!($atomic), "::compare_exchange_weak).")]
+            #[inline]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $ty,
+                new: $ty,
+                success: core::sync::atomic::Ordering,
+                failure: core::sync::atomic::Ordering,
+            ) -> Result<$ty, $ty> {
+                match self.0.compare_exchange_weak(current.get(), new.get(), success, failure) {
+                    Ok(n) => unsafe { Ok($ty::new_unchecked(n)) },
+                    Err(n) => unsafe { Err($ty::new_unchecked(n)) },
+                }
+            }
+            /// Fetches the value, and applies a function to it that returns an optional new value.
+            /// Returns a [`Result`] of [`Ok`]`(previous_value)` if the function returned
+            /// [`Some`]`(_)`, else [`Err`]`(previous_value)`.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::fetch_update`](core::sync::atomic::", stringify!($atomic), "::fetch_update).")]
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: core::sync::atomic::Ordering,
+                fetch_order: core::sync::atomic::Ordering,
+                mut f: F,
+            ) -> Result<$ty, $ty>
+            where
+                F: FnMut($ty) -> Option<$ty>,
+            {
+                let r = self.0.fetch_update(set_order, fetch_order, |n| {
+                    f(unsafe { $ty::new_unchecked(n) }).map(|n| n.get())
+                });
+                match r {
+                    Ok(n) => unsafe { Ok($ty::new_unchecked(n)) },
+                    Err(n) => unsafe { Err($ty::new_unchecked(n)) },
+                }
+            }
+            /// Maximum with the current value, returning the previous value.
+            ///
+            #[doc = concat!("The maximum of two ", $sign, " values is always ", $sign, ", so this never fails.")]
+            #[inline]
+            pub fn fetch_max(&self, value: $ty, order: core::sync::atomic::Ordering) -> $ty {
+                unsafe { $ty::new_unchecked(self.0.fetch_max(value.get(), order)) }
+            }
+            /// Minimum with the current value, returning the previous value.
+            ///
+            #[doc = concat!("The minimum of two ", $sign, " values is always ", $sign, ", so this never fails.")]
+            #[inline]
+            pub fn fetch_min(&self, value: $ty, order: core::sync::atomic::Ordering) -> $ty {
+                unsafe { $ty::new_unchecked(self.0.fetch_min(value.get(), order)) }
+            }
+            /// Saturating addition to the current value, returning the previous value.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::saturating_add`].")]
+            #[inline]
+            pub fn fetch_saturating_add(
+                &self,
+                value: $ty,
+                set_order: core::sync::atomic::Ordering,
+                fetch_order: core::sync::atomic::Ordering,
+            ) -> $ty {
+                match self.fetch_update(set_order, fetch_order, |n| Some(n.saturating_add(value))) {
+                    Ok(n) | Err(n) => n,
+                }
+            }
+            /// Saturating subtraction from the current value, returning the previous value.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::saturating_sub`].")]
+            #[inline]
+            pub fn fetch_saturating_sub(
+                &self,
+                value: $ty,
+                set_order: core::sync::atomic::Ordering,
+                fetch_order: core::sync::atomic::Ordering,
+            ) -> $ty {
+                match self.fetch_update(set_order, fetch_order, |n| Some(n.saturating_sub(value))) {
+                    Ok(n) | Err(n) => n,
+                }
+            }
+            /// Checked addition to the current value. Returns [`Ok`]`(previous_value)` if the
+            /// value was updated, or [`Err`]`(previous_value)` if the addition would overflow.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::checked_add`].")]
+            #[inline]
+            pub fn fetch_checked_add(
+                &self,
+                value: $ty,
+                set_order: core::sync::atomic::Ordering,
+                fetch_order: core::sync::atomic::Ordering,
+            ) -> Result<$ty, $ty> {
+                self.fetch_update(set_order, fetch_order, |n| n.checked_add(value))
+            }
+            /// Checked subtraction from the current value. Returns [`Ok`]`(previous_value)` if
+            /// the value was updated, or [`Err`]`(previous_value)` if the result would change
+            /// sign.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::checked_sub`].")]
+            #[inline]
+            pub fn fetch_checked_sub(
+                &self,
+                value: $ty,
+                set_order: core::sync::atomic::Ordering,
+                fetch_order: core::sync::atomic::Ordering,
+            ) -> Result<$ty, $ty> {
+                self.fetch_update(set_order, fetch_order, |n| n.checked_sub(value))
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl From<$ty> for $aty {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                Self::new(value)
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl core::fmt::Debug for $aty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.load(core::sync::atomic::Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+macro_rules! impl_atomic_option {
+    ($aty:ident, $ty:ident, $atomic:ident, $base:ty, $width:literal) => {
+        #[doc = concat!("An <code>[Option]<[", stringify!($ty), "]></code> which can be safely shared between threads.")]
+        ///
+        /// [`None`] is stored as `-1`.
+        #[cfg(target_has_atomic = $width)]
+        #[repr(transparent)]
+        pub struct $aty(core::sync::atomic::$atomic);
+
+        #[cfg(target_has_atomic = $width)]
+        impl $aty {
+            const NONE: $base = -1;
+            #[inline]
+            const fn encode(value: Option<$ty>) -> $base {
+                match value {
+                    Some(n) => n.get(),
+                    None => Self::NONE,
+                }
+            }
+            #[inline]
+            const fn decode(value: $base) -> Option<$ty> {
+                $ty::new(value)
+            }
+            #[doc = concat!("Creates a new `", stringify!($aty), "`.")]
+            #[inline]
+            pub const fn new(value: Option<$ty>) -> Self {
+                Self(core::sync::atomic::$atomic::new(Self::encode(value)))
+            }
+            /// Consumes the atomic and returns the contained value.
+            #[inline]
+            pub fn into_inner(self) -> Option<$ty> {
+                Self::decode(self.0.into_inner())
+            }
+            /// Loads a value from the atomic.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::load`](core::sync::atomic::", stringify!($atomic), "::load).")]
+            #[inline]
+            pub fn load(&self, order: core::sync::atomic::Ordering) -> Option<$ty> {
+                Self::decode(self.0.load(order))
+            }
+            /// Stores a value into the atomic.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::store`](core::sync::atomic::", stringify!($atomic), "::store).")]
+            #[inline]
+            pub fn store(&self, value: Option<$ty>, order: core::sync::atomic::Ordering) {
+                self.0.store(Self::encode(value), order)
+            }
+            /// Stores a value into the atomic, returning the previous value.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::swap`](core::sync::atomic::", stringify!($atomic), "::swap).")]
+            #[inline]
+            pub fn swap(&self, value: Option<$ty>, order: core::sync::atomic::Ordering) -> Option<$ty> {
+                Self::decode(self.0.swap(Self::encode(value), order))
+            }
+            /// Takes the value out of the atomic, leaving [`None`] in its place.
+            #[inline]
+            pub fn take(&self, order: core::sync::atomic::Ordering) -> Option<$ty> {
+                self.swap(None, order)
+            }
+            /// Stores a value into the atomic if the current value is the same as the `current`
+            /// value.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::compare_exchange`](core::sync::atomic::", stringify!($atomic), "::compare_exchange).")]
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: Option<$ty>,
+                new: Option<$ty>,
+                success: core::sync::atomic::Ordering,
+                failure: core::sync::atomic::Ordering,
+            ) -> Result<Option<$ty>, Option<$ty>> {
+                self.0
+                    .compare_exchange(Self::encode(current), Self::encode(new), success, failure)
+                    .map(Self::decode)
+                    .map_err(Self::decode)
+            }
+            /// Stores a value into the atomic if the current value is the same as the `current`
+            /// value. This function is allowed to spuriously fail.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::compare_exchange_weak`](core::sync::atomic::", stringify!($atomic), "::compare_exchange_weak).")]
+            #[inline]
+            pub fn compare_exchange_weak(
+                &self,
+                current: Option<$ty>,
+                new: Option<$ty>,
+                success: core::sync::atomic::Ordering,
+                failure: core::sync::atomic::Ordering,
+            ) -> Result<Option<$ty>, Option<$ty>> {
+                self.0
+                    .compare_exchange_weak(Self::encode(current), Self::encode(new), success, failure)
+                    .map(Self::decode)
+                    .map_err(Self::decode)
+            }
+            /// Fetches the value, and applies a function to it that returns an optional new value.
+            /// Returns a [`Result`] of [`Ok`]`(previous_value)` if the function returned
+            /// [`Some`]`(_)`, else [`Err`]`(previous_value)`.
+            ///
+            #[doc = concat!("See [`", stringify!($atomic), "::fetch_update`](core::sync::atomic::", stringify!($atomic), "::fetch_update).")]
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: core::sync::atomic::Ordering,
+                fetch_order: core::sync::atomic::Ordering,
+                mut f: F,
+            ) -> Result<Option<$ty>, Option<$ty>>
+            where
+                F: FnMut(Option<$ty>) -> Option<Option<$ty>>,
+            {
+                self.0
+                    .fetch_update(set_order, fetch_order, |n| f(Self::decode(n)).map(Self::encode))
+                    .map(Self::decode)
+                    .map_err(Self::decode)
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl Default for $aty {
+            #[inline]
+            fn default() -> Self {
+                Self::new(None)
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl From<Option<$ty>> for $aty {
+            #[inline]
+            fn from(value: Option<$ty>) -> Self {
+                Self::new(value)
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl core::fmt::Debug for $aty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.load(core::sync::atomic::Ordering::Relaxed), f)
+            }
+        }
+    };
 }
 
-#[derive(Copy, Clone)]
-#[repr(u8)]
-enum NegativeHighByte {
-    _128 = 128,
-    _129 = 129,
-    _130 = 130,
-    _131 = 131,
-    _132 = 132,
-    _133 = 133,
-    _134 = 134,
-    _135 = 135,
-    _136 = 136,
-    _137 = 137,
-    _138 = 138,
-    _139 = 139,
-    _140 = 140,
-    _141 = 141,
-    _142 = 142,
-    _143 = 143,
-    _144 = 144,
-    _145 = 145,
-    _146 = 146,
-    _147 = 147,
-    _148 = 148,
-    _149 = 149,
-    _150 = 150,
-    _151 = 151,
-    _152 = 152,
-    _153 = 153,
-    _154 = 154,
-    _155 = 155,
-    _156 = 156,
-    _157 = 157,
-    _158 = 158,
-    _159 = 159,
-    _160 = 160,
-    _161 = 161,
-    _162 = 162,
-    _163 = 163,
-    _164 = 164,
-    _165 = 165,
-    _166 = 166,
-    _167 = 167,
-    _168 = 168,
-    _169 = 169,
-    _170 = 170,
-    _171 = 171,
-    _172 = 172,
-    _173 = 173,
-    _174 = 174,
-    _175 = 175,
-    _176 = 176,
-    _177 = 177,
-    _178 = 178,
-    _179 = 179,
-    _180 = 180,
-    _181 = 181,
-    _182 = 182,
-    _183 = 183,
-    _184 = 184,
-    _185 = 185,
-    _186 = 186,
-    _187 = 187,
-    _188 = 188,
-    _189 = 189,
-    _190 = 190,
-    _191 = 191,
-    _192 = 192,
-    _193 = 193,
-    _194 = 194,
-    _195 = 195,
-    _196 = 196,
-    _197 = 197,
-    _198 = 198,
-    _199 = 199,
-    _200 = 200,
-    _201 = 201,
-    _202 = 202,
-    _203 = 203,
-    _204 = 204,
-    _205 = 205,
-    _206 = 206,
-    _207 = 207,
-    _208 = 208,
-    _209 = 209,
-    _210 = 210,
-    _211 = 211,
-    _212 = 212,
-    _213 = 213,
-    _214 = 214,
-    _215 = 215,
-    _216 = 216,
-    _217 = 217,
-    _218 = 218,
-    _219 = 219,
-    _220 = 220,
-    _221 = 221,
-    _222 = 222,
-    _223 = 223,
-    _224 = 224,
-    _225 = 225,
-    _226 = 226,
-    _227 = 227,
-    _228 = 228,
-    _229 = 229,
-    _230 = 230,
-    _231 = 231,
-    _232 = 232,
-    _233 = 233,
-    _234 = 234,
-    _235 = 235,
-    _236 = 236,
-    _237 = 237,
-    _238 = 238,
-    _239 = 239,
-    _240 = 240,
-    _241 = 241,
-    _242 = 242,
-    _243 = 243,
-    _244 = 244,
-    _245 = 245,
-    _246 = 246,
-    _247 = 247,
-    _248 = 248,
-    _249 = 249,
-    _250 = 250,
-    _251 = 251,
-    _252 = 252,
-    _253 = 253,
-    _254 = 254,
-    _255 = 255,
-}
+macro_rules! impl_optional_index {
+    ($ty:ident, $pty:ident, $base:ident) => {
+        #[doc = concat!("An optional index stored in an [`", stringify!($base), "`].")]
+        ///
+        #[doc = concat!("This behaves like <code>[Option]<[", stringify!($pty), "]></code>, but with an API for")]
+        /// indexing into slices. The raw value of [`NONE`](Self::NONE) is guaranteed to be `-1`,
+        /// so this can be passed through FFI or stored in serialized structures.
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $ty($base);
+
+        impl $ty {
+            /// The empty index. The raw value of this constant is always `-1`.
+            pub const NONE: Self = Self(-1);
+            #[doc = concat!("Creates an index from an optional [`", stringify!($pty), "`].")]
+            #[inline]
+            pub const fn new(index: Option<$pty>) -> Self {
+                match index {
+                    Some(index) => Self(index.get()),
+                    None => Self::NONE,
+                }
+            }
+            #[doc = concat!("Returns the index as an optional [`", stringify!($pty), "`].")]
+            #[inline]
+            pub const fn get(self) -> Option<$pty> {
+                $pty::new(self.0)
+            }
+            #[doc = concat!("Creates an index from a `usize`, or returns `None` if the index is larger than [`", stringify!($base), "::MAX`].")]
+            #[inline]
+            pub const fn from_usize(index: usize) -> Option<Self> {
+                if index > $base::MAX as usize {
+                    return None;
+                }
+                Some(Self(index as $base))
+            }
+            /// Returns the index as a `usize`, or `None` if the index is empty.
+            #[inline]
+            pub const fn to_usize(self) -> Option<usize> {
+                if self.0 < 0 {
+                    return None;
+                }
+                Some(self.0 as usize)
+            }
+            /// Returns `true` if the index is empty.
+            #[inline]
+            pub const fn is_none(self) -> bool {
+                self.0 < 0
+            }
+            /// Returns `true` if the index is not empty.
+            #[inline]
+            pub const fn is_some(self) -> bool {
+                self.0 >= 0
+            }
+            /// Returns a reference to the element of `slice` at this index, or `None` if the index
+            /// is empty or out of bounds.
+            #[inline]
+            pub fn get_in<T>(self, slice: &[T]) -> Option<&T> {
+                slice.get(self.to_usize()?)
+            }
+            /// Returns a mutable reference to the element of `slice` at this index, or `None` if
+            /// the index is empty or out of bounds.
+            #[inline]
+            pub fn get_mut_in<T>(self, slice: &mut [T]) -> Option<&mut T> {
+                slice.get_mut(self.to_usize()?)
+            }
+            /// Takes the index out, leaving [`NONE`](Self::NONE) in its place.
+            #[inline]
+            pub fn take(&mut self) -> Self {
+                core::mem::replace(self, Self::NONE)
+            }
+            /// Replaces the index with `index`, returning the old index.
+            #[inline]
+            pub fn replace(&mut self, index: $pty) -> Self {
+                core::mem::replace(self, Self(index.get()))
+            }
+            /// Returns the raw value. This is `-1` if the index is empty.
+            #[inline]
+            pub const fn to_raw(self) -> $base {
+                self.0
+            }
+            /// Creates an index from a raw value, or returns `None` if the value is negative and
+            /// not `-1`.
+            #[inline]
+            pub const fn from_raw(raw: $base) -> Option<Self> {
+                if raw < -1 {
+                    return None;
+                }
+                Some(Self(raw))
+            }
+            /// Creates an index from a raw value without checking it.
+            ///
+            /// # Safety
+            ///
+            /// The value must not be less than `-1`.
+            #[inline]
+            pub const unsafe fn from_raw_unchecked(raw: $base) -> Self {
+                debug_assert!(raw >= -1);
+                Self(raw)
+            }
+        }
+
+        impl Default for $ty {
+            #[inline]
+            fn default() -> Self {
+                Self::NONE
+            }
+        }
+
+        impl core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl From<$pty> for $ty {
+            #[inline]
+            fn from(index: $pty) -> Self {
+                Self(index.get())
+            }
+        }
+
+        impl From<Option<$pty>> for $ty {
+            #[inline]
+            fn from(index: Option<$pty>) -> Self {
+                Self::new(index)
+            }
+        }
+
+        impl From<$ty> for Option<$pty> {
+            #[inline]
+            fn from(index: $ty) -> Self {
+                index.get()
+            }
+        }
+    };
+}
+
+macro_rules! impl_unsigned {
+    ($ty:ident, $pty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
+
+        #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
+        ///
+        #[doc = concat!("This macro takes a single argument that must be a [`", stringify!($uns), "`] no larger than [`", stringify!($ty), "::MAX`].")]
+        /// Larger values will cause a compile error.
+        ///
+        /// ```rust
+        #[doc = concat!("use sign_bound::{", stringify!($ty), ", ", stringify!($mac), "};")]
+        ///
+        #[doc = concat!("let val: ", stringify!($ty), " = ", stringify!($mac), "!(123);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $mac {
+            ($e:expr $d(,)?) => {
+                const {
+                    match $crate::$ty::new($e) {
+                        ::core::option::Option::Some(e) => e,
+                        _ => panic!(concat!(stringify!($uns), " out of range for ", stringify!($ty))),
+                    }
+                }
+            };
+        }
+
+        #[doc = concat!("An unsigned integer that is one bit smaller than [`", stringify!($uns), "`].")]
+        ///
+        #[doc = concat!("This has the same memory layout as [`", stringify!($pty), "`] and can be converted to and from it at")]
+        /// no cost, but presents an unsigned API. Wrapping arithmetic is performed modulo
+        #[doc = concat!("<code>2<sup>", stringify!($ty), "::BITS</sup></code>.")]
+        ///
+        /// This enables some memory layout optimization.
+        #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($uns), "`].")]
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        pub struct $ty($pty);
+
+        impl $ty {
+            /// The size of this integer type in bits.
+            ///
+            #[doc = concat!("This value is one less than [`", stringify!($uns), "::BITS`].")]
+            pub const BITS: u32 = <$uns>::BITS - 1;
+            /// The smallest value that can be represented by this integer type, 0.
+            pub const MIN: Self = Self($pty::MIN);
+            #[doc = concat!("The largest value that can be represented by this integer type, equal to [`", stringify!($base), "::MAX`].")]
+            pub const MAX: Self = Self($pty::MAX);
+            const MASK: $uns = <$base>::MAX as $uns;
+            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is no larger than [`", stringify!($ty), "::MAX`].")]
+            #[inline]
+            pub const fn new(value: $uns) -> Option<Self> {
+                match $pty::new(value as $base) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is in range.")]
+            #[doc = concat!("This results in undefined behaviour if the value is larger than [`", stringify!($ty), "::MAX`].")]
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("The value must not be larger than [`", stringify!($ty), "::MAX`].")]
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $uns) -> Self {
+                Self($pty::new_unchecked(value as $base))
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` from the low [`", stringify!($ty), "::BITS`] bits of the given value.")]
+            #[inline]
+            pub const fn new_wrapping(value: $uns) -> Self {
+                unsafe { Self::new_unchecked(value & Self::MASK) }
+            }
+            /// Returns the contained value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $uns {
+                self.0.get() as $uns
+            }
+            #[doc = concat!("Returns the contained value as a [`", stringify!($pty), "`].")]
+            #[inline]
+            pub const fn to_positive(self) -> $pty {
+                self.0
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` from a [`", stringify!($pty), "`].")]
+            #[inline]
+            pub const fn from_positive(value: $pty) -> Self {
+                Self(value)
+            }
+            /// Returns the number of zeros in the binary representation of `self`.
+            ///
+            /// Only the low [`BITS`](Self::BITS) bits are counted.
+            #[inline]
+            pub const fn count_zeros(self) -> u32 {
+                self.get().count_zeros() - 1
+            }
+            /// Returns the number of ones in the binary representation of `self`.
+            #[inline]
+            pub const fn count_ones(self) -> u32 {
+                self.get().count_ones()
+            }
+            /// Returns the number of leading zeros in the binary representation of `self`.
+            ///
+            /// Only the low [`BITS`](Self::BITS) bits are counted.
+            #[inline]
+            pub const fn leading_zeros(self) -> u32 {
+                self.get().leading_zeros() - 1
+            }
+            /// Returns the number of trailing zeros in the binary representation of `self`.
+            ///
+            #[doc = concat!("Returns [`", stringify!($ty), "::BITS`] if `self` is zero.")]
+            #[inline]
+            pub const fn trailing_zeros(self) -> u32 {
+                let n = self.get().trailing_zeros();
+                if n > Self::BITS {
+                    Self::BITS
+                } else {
+                    n
+                }
+            }
+            /// Returns `true` if and only if `self == (1 << k)` for some `k`.
+            #[inline]
+            pub const fn is_power_of_two(self) -> bool {
+                self.get().is_power_of_two()
+            }
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is zero.
+            #[inline]
+            pub const fn ilog2(self) -> u32 {
+                self.get().ilog2()
+            }
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is zero.
+            #[inline]
+            pub const fn ilog10(self) -> u32 {
+                self.get().ilog10()
+            }
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is zero.
+            #[inline]
+            pub const fn checked_ilog2(self) -> Option<u32> {
+                self.get().checked_ilog2()
+            }
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is zero.
+            #[inline]
+            pub const fn checked_ilog10(self) -> Option<u32> {
+                self.get().checked_ilog10()
+            }
+            /// Checked addition. Returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_add(rhs.0) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            /// Checked subtraction. Returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_sub(rhs.0) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            /// Checked multiplication. Returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_mul(rhs.0) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            /// Checked division. Returns [`None`] if `rhs == 0`.
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_div(rhs.0) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            /// Checked remainder. Returns [`None`] if `rhs == 0`.
+            #[inline]
+            pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_rem(rhs.0) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            /// Checked integer exponentiation. Returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_pow(self, rhs: u32) -> Option<Self> {
+                match self.0.checked_pow(rhs) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+            /// Saturating addition.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+            /// Saturating subtraction. Returns 0 on overflow.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+            /// Saturating multiplication.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                Self(self.0.saturating_mul(rhs.0))
+            }
+            /// Saturating integer exponentiation.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_pow(self, rhs: u32) -> Self {
+                Self(self.0.saturating_pow(rhs))
+            }
+            /// Wrapping addition, modulo
+            #[doc = concat!("<code>2<sup>", stringify!($ty), "::BITS</sup></code>.")]
+            #[inline]
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                Self::new_wrapping(self.get().wrapping_add(rhs.get()))
+            }
+            /// Wrapping subtraction, modulo
+            #[doc = concat!("<code>2<sup>", stringify!($ty), "::BITS</sup></code>.")]
+            #[inline]
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                Self::new_wrapping(self.get().wrapping_sub(rhs.get()))
+            }
+            /// Wrapping multiplication, modulo
+            #[doc = concat!("<code>2<sup>", stringify!($ty), "::BITS</sup></code>.")]
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                Self::new_wrapping(self.get().wrapping_mul(rhs.get()))
+            }
+            /// Wrapping negation, modulo
+            #[doc = concat!("<code>2<sup>", stringify!($ty), "::BITS</sup></code>.")]
+            #[inline]
+            pub const fn wrapping_neg(self) -> Self {
+                Self::new_wrapping(self.get().wrapping_neg())
+            }
+            /// Wrapping integer exponentiation, modulo
+            #[doc = concat!("<code>2<sup>", stringify!($ty), "::BITS</sup></code>.")]
+            #[inline]
+            pub const fn wrapping_pow(self, rhs: u32) -> Self {
+                Self::new_wrapping(self.get().wrapping_pow(rhs))
+            }
+        }
+
+        impl Default for $ty {
+            #[inline]
+            fn default() -> Self {
+                Self::MIN
+            }
+        }
+
+        impl PartialEq for $ty {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.get().eq(&rhs.get())
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $ty {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&rhs.get())
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::str::FromStr for $ty {
+            type Err = core::num::IntErrorKind;
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let n = s.parse::<$uns>().map_err(|e| e.kind().clone())?;
+                Self::new(n).ok_or_else(|| core::num::IntErrorKind::PosOverflow)
+            }
+        }
+
+        impl core::hash::Hash for $ty {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+
+        impl From<$ty> for $pty {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.0
+            }
+        }
+        impl From<$pty> for $ty {
+            #[inline]
+            fn from(value: $pty) -> Self {
+                Self(value)
+            }
+        }
+
+        impl_bit_op! { BitOr::bitor, BitOrAssign::bitor_assign for $ty }
+        impl_bit_op! { BitAnd::bitand, BitAndAssign::bitand_assign for $ty }
+        impl_bit_op! { BitXor::bitxor, BitXorAssign::bitxor_assign for $ty }
+        impl_fmt! { Display, Debug, Binary, Octal, LowerHex, UpperHex => $ty }
+    };
+}
+
+macro_rules! impl_bounded {
+    (
+        $(#[$attr:meta])*
+        $ty:ident($inner:ty), $base:ty, $uns:ty, $lo:expr, $hi:expr,
+        |$wv:ident| $wrap:expr, |$uv:ident| $unwrap:expr
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        pub struct $ty<const MIN: $base, const MAX: $base>($inner);
+
+        impl<const MIN: $base, const MAX: $base> $ty<MIN, MAX> {
+            const VALID: () = assert!(
+                $lo <= MIN && MIN <= MAX && MAX <= $hi,
+                concat!("invalid bounds for ", stringify!($ty)),
+            );
+            /// The size of this bounded integer type in bits.
+            ///
+            #[doc = concat!("This value is equal to [`", stringify!($base), "::BITS`].")]
+            pub const BITS: u32 = <$base>::BITS;
+            /// The smallest value that can be represented by this bounded integer type, `MIN`.
+            pub const MIN: Self = unsafe { Self::new_unchecked(MIN) };
+            /// The largest value that can be represented by this bounded integer type, `MAX`.
+            pub const MAX: Self = unsafe { Self::new_unchecked(MAX) };
+            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is within `MIN..=MAX`.")]
+            pub const fn new(value: $base) -> Option<Self> {
+                if value < MIN || value > MAX {
+                    return None;
+                }
+                unsafe { Some(Self::new_unchecked(value)) }
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is within `MIN..=MAX`.")]
+            /// This results in undefined behaviour if the value is out of range.
+            ///
+            /// # Safety
+            ///
+            /// The value must be within `MIN..=MAX`.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $base) -> Self {
+                let () = Self::VALID;
+                debug_assert!(MIN <= value && value <= MAX);
+                let $wv = value;
+                Self($wrap)
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` by clamping the given value to `MIN..=MAX`.")]
+            #[inline]
+            pub const fn new_saturating(value: $base) -> Self {
+                let value = if value < MIN {
+                    MIN
+                } else if value > MAX {
+                    MAX
+                } else {
+                    value
+                };
+                unsafe { Self::new_unchecked(value) }
+            }
+            /// Returns the contained value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $base {
+                let $uv = self.0;
+                let n = $unwrap;
+                unsafe { core::hint::assert_unchecked(MIN <= n && n <= MAX) };
+                n
+            }
+            /// Returns a number representing the sign of `self`.
+            ///
+            /// - `0` if the number is zero
+            /// - `1` if the number is positive
+            /// - `-1` if the number is negative
+            #[inline]
+            pub const fn signum(self) -> $base {
+                self.get().signum()
+            }
+            /// Checked addition. Adds a primitive integer to a bounded integer.
+            /// Returns [`None`] if the result would overflow or fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn checked_add(self, rhs: $base) -> Option<Self> {
+                match self.get().checked_add(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Checked subtraction. Subtracts a primitive integer from a bounded integer.
+            /// Returns [`None`] if the result would overflow or fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn checked_sub(self, rhs: $base) -> Option<Self> {
+                match self.get().checked_sub(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Checked multiplication. Multiplies a bounded integer by a primitive integer.
+            /// Returns [`None`] if the result would overflow or fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn checked_mul(self, rhs: $base) -> Option<Self> {
+                match self.get().checked_mul(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Checked division. Divides a bounded integer by a primitive integer.
+            /// Returns [`None`] if `rhs == 0`, or if the result would overflow or fall outside of
+            /// `MIN..=MAX`.
+            #[inline]
+            pub const fn checked_div(self, rhs: $base) -> Option<Self> {
+                match self.get().checked_div(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Checked negation. Computes `-self`, returning [`None`] if the result would overflow
+            /// or fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn checked_neg(self) -> Option<Self> {
+                match self.get().checked_neg() {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Checked integer exponentiation. Raises a bounded integer to an integer power.
+            /// Returns [`None`] if the result would overflow or fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn checked_pow(self, rhs: u32) -> Option<Self> {
+                match self.get().checked_pow(rhs) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Saturating addition. Adds a primitive integer to a bounded integer.
+            /// Returns `MIN` or `MAX` if the result would fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn saturating_add(self, rhs: $base) -> Self {
+                Self::new_saturating(self.get().saturating_add(rhs))
+            }
+            /// Saturating subtraction. Subtracts a primitive integer from a bounded integer.
+            /// Returns `MIN` or `MAX` if the result would fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: $base) -> Self {
+                Self::new_saturating(self.get().saturating_sub(rhs))
+            }
+            /// Saturating multiplication. Multiplies a bounded integer by a primitive integer.
+            /// Returns `MIN` or `MAX` if the result would fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn saturating_mul(self, rhs: $base) -> Self {
+                Self::new_saturating(self.get().saturating_mul(rhs))
+            }
+            /// Saturating integer exponentiation. Raises a bounded integer to an integer power.
+            /// Returns `MIN` or `MAX` if the result would fall outside of `MIN..=MAX`.
+            #[inline]
+            pub const fn saturating_pow(self, rhs: u32) -> Self {
+                Self::new_saturating(self.get().saturating_pow(rhs))
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> PartialEq for $ty<MIN, MAX> {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.get().eq(&rhs.get())
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> PartialOrd for $ty<MIN, MAX> {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> Ord for $ty<MIN, MAX> {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&rhs.get())
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> Eq for $ty<MIN, MAX> {}
+
+        impl<const MIN: $base, const MAX: $base> core::hash::Hash for $ty<MIN, MAX> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> core::str::FromStr for $ty<MIN, MAX> {
+            type Err = core::num::IntErrorKind;
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let n = s.parse::<$base>().map_err(|e| e.kind().clone())?;
+                if n < MIN {
+                    return Err(core::num::IntErrorKind::NegOverflow);
+                }
+                Self::new(n).ok_or_else(|| core::num::IntErrorKind::PosOverflow)
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> From<$ty<MIN, MAX>> for $base {
+            #[inline]
+            fn from(value: $ty<MIN, MAX>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<const MIN: $base, const MAX: $base> TryFrom<$base> for $ty<MIN, MAX> {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: $base) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or_else(|| <$base>::try_from(<$uns>::MAX).unwrap_err())
+            }
+        }
+
+        impl_fmt! {
+            Display, Debug, Binary, Octal, LowerHex, UpperHex
+            => $ty<MIN, MAX> where [const MIN: $base, const MAX: $base]
+        }
+    };
+}
+
+macro_rules! impl_bounded_all {
+    ($bty:ident, $bpty:ident($pty:ident), $bnty:ident($nty:ident), $base:ty, $uns:ty) => {
+        impl_bounded! {
+            /// A signed value that is known to be within the range `MIN..=MAX`.
+            ///
+            #[doc = concat!("Unlike [`", stringify!($bpty), "`] and [`", stringify!($bnty), "`], the range may include")]
+            /// both positive and negative values, so no memory layout optimization is possible.
+            $bty($base), $base, $uns, <$base>::MIN, <$base>::MAX, |v| v, |v| v
+        }
+        impl_bounded! {
+            /// A signed value that is known to be positive and within the range `MIN..=MAX`.
+            ///
+            /// This enables some memory layout optimization.
+            #[doc = concat!("For example, `Option<", stringify!($bpty), "<0, 1000>>` is the same size as [`", stringify!($base), "`].")]
+            ///
+            /// Using a negative `MIN` will cause a compile error.
+            $bpty($pty), $base, $uns, 0, <$base>::MAX,
+            |v| $pty::new_unchecked(v), |v| v.get()
+        }
+        impl_bounded! {
+            /// A signed value that is known to be negative and within the range `MIN..=MAX`.
+            ///
+            /// This enables some memory layout optimization.
+            #[doc = concat!("For example, `Option<", stringify!($bnty), "<-1000, -1>>` is the same size as [`", stringify!($base), "`].")]
+            ///
+            /// Using a positive `MAX` will cause a compile error.
+            $bnty($nty), $base, $uns, <$base>::MIN, -1,
+            |v| $nty::new_unchecked(v), |v| v.get()
+        }
+
+        impl<const MIN: $base, const MAX: $base> From<$bpty<MIN, MAX>> for $bty<MIN, MAX> {
+            #[inline]
+            fn from(value: $bpty<MIN, MAX>) -> Self {
+                unsafe { Self::new_unchecked(value.get()) }
+            }
+        }
+        impl<const MIN: $base, const MAX: $base> From<$bnty<MIN, MAX>> for $bty<MIN, MAX> {
+            #[inline]
+            fn from(value: $bnty<MIN, MAX>) -> Self {
+                unsafe { Self::new_unchecked(value.get()) }
+            }
+        }
+        impl<const MIN: $base, const MAX: $base> From<$bpty<MIN, MAX>> for $pty {
+            #[inline]
+            fn from(value: $bpty<MIN, MAX>) -> Self {
+                value.0
+            }
+        }
+        impl<const MIN: $base, const MAX: $base> From<$bnty<MIN, MAX>> for $nty {
+            #[inline]
+            fn from(value: $bnty<MIN, MAX>) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+macro_rules! impl_non_positive {
+    ($(#[$attr:meta])* $ty:ident, $nty:ident, $pty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
+
+        #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
+        ///
+        #[doc = concat!("This macro takes a single argument that must be a non-positive [`", stringify!($base), "`].")]
+        /// Values greater than zero will cause a compile error.
+        ///
+        /// ```rust
+        #[doc = concat!("use sign_bound::{", stringify!($ty), ", ", stringify!($mac), "};")]
+        ///
+        #[doc = concat!("let val: ", stringify!($ty), " = ", stringify!($mac), "!(-123);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $mac {
+            ($e:expr $d(,)?) => {
+                const {
+                    match $crate::$ty::new($e) {
+                        ::core::option::Option::Some(e) => e,
+                        _ => panic!(concat!(stringify!($base), " out of range for ", stringify!($ty))),
+                    }
+                }
+            };
+        }
+
+        /// A signed value that is known to be negative or zero.
+        ///
+        /// This enables some memory layout optimization.
+        #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($base), "`].")]
+        #[derive(Copy, Clone)]
+        $(#[$attr])*
+        #[repr(C)]
+        pub struct $ty {
+            #[cfg(target_endian = "big")]
+            _hi: NonPositiveHighByte,
+            _buf: [u8; size_of::<$base>() - 1],
+            #[cfg(target_endian = "little")]
+            _hi: NonPositiveHighByte,
+        }
+
+        impl $ty {
+            /// The size of this non-positive integer type in bits.
+            ///
+            #[doc = concat!("This value is equal to [`", stringify!($base), "::BITS`].")]
+            pub const BITS: u32 = <$base>::BITS;
+            #[doc = concat!("The smallest value that can be represented by this non-positive integer type, equal to [`", stringify!($base), "::MIN`].")]
+            pub const MIN: Self = unsafe { $ty::new_unchecked(<$base>::MIN) };
+            /// The largest value that can be represented by this non-positive integer type, 0.
+            pub const MAX: Self = unsafe { $ty::new_unchecked(0) };
+            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is negative or zero.")]
+            pub const fn new(value: $base) -> Option<Self> {
+                if value > 0 {
+                    return None;
+                }
+                unsafe { Some(core::mem::transmute::<$base, Self>(value)) }
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is negative or zero.")]
+            /// This results in undefined behaviour if the value is greater than zero.
+            ///
+            /// # Safety
+            ///
+            /// The value must not be greater than zero.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $base) -> Self {
+                debug_assert!(value <= 0);
+                core::mem::transmute::<$base, Self>(value)
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` by negating a [`", stringify!($pty), "`].")]
+            ///
+            /// This can never overflow, since every positive value has a negative counterpart.
+            #[inline]
+            pub const fn from_neg(value: $pty) -> Self {
+                unsafe { Self::new_unchecked(-value.get()) }
+            }
+            /// Returns the contained value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $base {
+                unsafe {
+                    let n = core::mem::transmute::<Self, $base>(self);
+                    core::hint::assert_unchecked(n <= 0);
+                    n
+                }
+            }
+            /// Returns a number representing the sign of `self`.
+            ///
+            /// - `0` if the number is zero
+            /// - `-1` if the number is negative
+            #[inline]
+            pub const fn signum(self) -> $base {
+                -((self.get() != 0) as $base)
+            }
+            /// Returns the number of zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn count_zeros(self) -> u32 {
+                self.get().count_zeros()
+            }
+            /// Returns the number of ones in the binary representation of `self`.
+            #[inline]
+            pub const fn count_ones(self) -> u32 {
+                self.get().count_ones()
+            }
+            /// Returns the number of leading zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn leading_zeros(self) -> u32 {
+                self.get().leading_zeros()
+            }
+            /// Returns the number of trailing zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn trailing_zeros(self) -> u32 {
+                self.get().trailing_zeros()
+            }
+            /// Checked absolute value.
+            /// Computes `-self`, returning [`None`] if <code>self == [MIN][Self::MIN]</code>.
+            #[inline]
+            pub const fn checked_abs(self) -> Option<$pty> {
+                match self.get().checked_abs() {
+                    Some(n) => unsafe { Some($pty::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Checked negation.
+            /// Computes `-self`, returning [`None`] if <code>self == [MIN][Self::MIN]</code>.
+            #[inline]
+            pub const fn checked_neg(self) -> Option<$pty> {
+                match self.get().checked_neg() {
+                    Some(n) => unsafe { Some($pty::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Checked addition. Adds a non-positive integer to another non-positive integer.
+            /// Checks for overflow and returns [`None`] on overflow.
+            /// As a consequence, the result cannot wrap to positive integers.
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.get().checked_add(rhs.get()) {
+                    Some(n) => unsafe { Some(Self::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Checked subtraction. Subtracts a non-positive integer from another non-positive
+            /// integer. Returns [`None`] if the result would be greater than zero.
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.get().checked_sub(rhs.get()) {
+                    Some(n) => Self::new(n),
+                    None => None,
+                }
+            }
+            /// Checked multiplication.
+            /// Multiplies a non-positive integer by another non-positive integer, returning a
+            /// positive result. Returns [`None`] if the result would overflow.
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<$pty> {
+                match self.get().checked_mul(rhs.get()) {
+                    Some(n) => unsafe { Some($pty::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Checked sign-preserving multiplication. Multiplies a non-positive integer by a
+            /// positive integer, returning a non-positive result.
+            /// Returns [`None`] if the result would overflow.
+            #[inline]
+            pub const fn checked_mul_positive(self, rhs: $pty) -> Option<Self> {
+                match self.get().checked_mul(rhs.get()) {
+                    Some(n) => unsafe { Some(Self::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Checked division.
+            /// Divides a non-positive integer by a negative integer, returning the positive
+            /// quotient. Returns [`None`] if the result would overflow.
+            ///
+            /// The only case where such an overflow can occur is when one divides
+            /// <code>[MIN][Self::MIN] / -1</code>; this is equivalent to
+            /// <code>-[MIN][Self::MIN]</code>, a positive value that is too large to represent
+            #[doc = concat!("as a [`", stringify!($pty), "`].")]
+            #[inline]
+            pub const fn checked_div(self, rhs: $nty) -> Option<$pty> {
+                match self.get().checked_div(rhs.get()) {
+                    Some(n) => unsafe { Some($pty::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Saturating absolute value.
+            /// Computes `-self`, returning
+            #[doc = concat!("[`", stringify!($pty), "::MAX`]")]
+            /// if <code>self == [MIN][Self::MIN]</code>.
+            #[inline]
+            pub const fn saturating_abs(self) -> $pty {
+                let n = self.get().saturating_abs();
+                unsafe { $pty::new_unchecked(n) }
+            }
+            /// Saturating negation.
+            /// Computes `-self`, returning
+            #[doc = concat!("[`", stringify!($pty), "::MAX`]")]
+            /// if <code>self == [MIN][Self::MIN]</code>.
+            #[inline]
+            pub const fn saturating_neg(self) -> $pty {
+                let n = self.get().saturating_neg();
+                unsafe { $pty::new_unchecked(n) }
+            }
+            /// Saturating addition. Adds a non-positive integer to another non-positive integer.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MIN`] on overflow.")]
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                let n = self.get().saturating_add(rhs.get());
+                unsafe { Self::new_unchecked(n) }
+            }
+            /// Saturating subtraction. Subtracts a non-positive integer from another non-positive
+            /// integer. Returns 0 if the result would be greater than zero.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match Self::new(self.get().saturating_sub(rhs.get())) {
+                    Some(n) => n,
+                    None => Self::MAX,
+                }
+            }
+            /// Saturating multiplication.
+            /// Multiplies a non-positive integer by another non-positive integer, returning a
+            /// positive result.
+            #[doc = concat!("Returns [`", stringify!($pty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> $pty {
+                let n = self.get().saturating_mul(rhs.get());
+                unsafe { $pty::new_unchecked(n) }
+            }
+            /// Saturating sign-preserving multiplication.
+            /// Multiplies a non-positive integer by a positive integer, returning a non-positive
+            /// result.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MIN`] on overflow.")]
+            #[inline]
+            pub const fn saturating_mul_positive(self, rhs: $pty) -> Self {
+                let n = self.get().saturating_mul(rhs.get());
+                unsafe { Self::new_unchecked(n) }
+            }
+        }
+
+        impl Default for $ty {
+            #[inline]
+            fn default() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl PartialEq for $ty {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.get().eq(&rhs.get())
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $ty {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&rhs.get())
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::str::FromStr for $ty {
+            type Err = core::num::IntErrorKind;
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let n = s.parse::<$base>().map_err(|e| e.kind().clone())?;
+                Self::new(n).ok_or_else(|| core::num::IntErrorKind::PosOverflow)
+            }
+        }
+
+        impl core::hash::Hash for $ty {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+
+        impl From<$nty> for $ty {
+            #[inline]
+            fn from(value: $nty) -> Self {
+                unsafe { Self::new_unchecked(value.get()) }
+            }
+        }
+
+        impl core::iter::Sum<$nty> for $ty {
+            #[inline]
+            #[track_caller]
+            fn sum<I: Iterator<Item = $nty>>(iter: I) -> Self {
+                let mut iter = iter.peekable();
+                match iter.peek() {
+                    Some(_) => <$nty as core::iter::Sum>::sum(iter).into(),
+                    None => Self::MAX,
+                }
+            }
+        }
+        impl<'a> core::iter::Sum<&'a $nty> for $ty {
+            #[inline]
+            #[track_caller]
+            fn sum<I: Iterator<Item = &'a $nty>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+        impl TryFrom<$ty> for $nty {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: $ty) -> Result<Self, Self::Error> {
+                Self::new(value.get()).ok_or_else(|| <$base>::try_from(<$uns>::MAX).unwrap_err())
+            }
+        }
+
+        impl_fmt! { Display, Debug, Binary, Octal, LowerHex, UpperHex => $ty }
+        impl_bit_op! { BitOr::bitor, BitOrAssign::bitor_assign for $ty }
+    };
+}
+
+macro_rules! impl_strictly_positive {
+    ($ty:ident, $pty:ident, $d:tt $mac:ident, $base:ty, $uns:ty) => {
+
+        #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
+        ///
+        #[doc = concat!("This macro takes a single argument that must be a strictly positive [`", stringify!($base), "`].")]
+        /// Zero or negative values will cause a compile error.
+        ///
+        /// ```rust
+        #[doc = concat!("use sign_bound::{", stringify!($ty), ", ", stringify!($mac), "};")]
+        ///
+        #[doc = concat!("let val: ", stringify!($ty), " = ", stringify!($mac), "!(123);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $mac {
+            ($e:expr $d(,)?) => {
+                const {
+                    match $crate::$ty::new($e) {
+                        ::core::option::Option::Some(e) => e,
+                        _ => panic!(concat!(stringify!($base), " out of range for ", stringify!($ty))),
+                    }
+                }
+            };
+        }
+
+        /// A signed value that is known to be strictly positive, i.e. greater than zero.
+        ///
+        /// This enables some memory layout optimization.
+        #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($base), "`].")]
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        pub struct $ty($pty);
+
+        impl $ty {
+            /// The size of this strictly positive integer type in bits.
+            ///
+            #[doc = concat!("This value is equal to [`", stringify!($base), "::BITS`].")]
+            pub const BITS: u32 = <$base>::BITS;
+            /// The smallest value that can be represented by this strictly positive integer type, 1.
+            pub const MIN: Self = unsafe { $ty::new_unchecked(1) };
+            #[doc = concat!("The largest value that can be represented by this strictly positive integer type, equal to [`", stringify!($base), "::MAX`].")]
+            pub const MAX: Self = unsafe { $ty::new_unchecked(<$base>::MAX) };
+            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is greater than zero.")]
+            pub const fn new(value: $base) -> Option<Self> {
+                if value <= 0 {
+                    return None;
+                }
+                unsafe { Some(Self($pty::new_unchecked(value))) }
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is greater than zero.")]
+            /// This results in undefined behaviour if the value is zero or negative.
+            ///
+            /// # Safety
+            ///
+            /// The value must be greater than zero.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $base) -> Self {
+                debug_assert!(value > 0);
+                Self($pty::new_unchecked(value))
+            }
+            /// Returns the contained value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $base {
+                let n = self.0.get();
+                unsafe { core::hint::assert_unchecked(n > 0) };
+                n
+            }
+            #[doc = concat!("Returns the contained value as a [`", stringify!($pty), "`].")]
+            #[inline]
+            pub const fn to_positive(self) -> $pty {
+                self.0
+            }
+            /// Returns a number representing the sign of `self`.
+            ///
+            /// Since the value is guaranteed to be greater than zero, this function always returns 1.
+            #[inline]
+            pub const fn signum(self) -> $base {
+                1
+            }
+            /// Returns the number of zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn count_zeros(self) -> u32 {
+                self.get().count_zeros()
+            }
+            /// Returns the number of ones in the binary representation of `self`.
+            #[inline]
+            pub const fn count_ones(self) -> u32 {
+                self.get().count_ones()
+            }
+            /// Returns the number of leading zeros in the binary representation of `self`.
+            #[inline]
+            pub const fn leading_zeros(self) -> u32 {
+                self.get().leading_zeros()
+            }
+            /// Returns the number of trailing zeros in the binary representation of `self`.
+            ///
+            /// On many architectures, this function can perform better than `trailing_zeros()` on
+            /// the underlying integer type, as special handling of zero can be avoided.
+            #[inline]
+            pub const fn trailing_zeros(self) -> u32 {
+                self.get().trailing_zeros()
+            }
+            /// Returns `true` if and only if `self == (1 << k)` for some `k`.
+            #[inline]
+            pub const fn is_power_of_two(self) -> bool {
+                (self.get() as $uns).is_power_of_two()
+            }
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// This function never panics, as the value can never be zero.
+            #[inline]
+            pub const fn ilog2(self) -> u32 {
+                Self::BITS - 1 - self.leading_zeros()
+            }
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// This function never panics, as the value can never be zero.
+            #[inline]
+            pub const fn ilog10(self) -> u32 {
+                match self.get().checked_ilog10() {
+                    Some(n) => n,
+                    None => unsafe { core::hint::unreachable_unchecked() },
+                }
+            }
+            /// Checked addition. Adds a strictly positive integer to another strictly positive
+            /// integer. Checks for overflow and returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.get().checked_add(rhs.get()) {
+                    Some(n) => unsafe { Some(Self::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Checked subtraction. Subtracts a strictly positive integer from another strictly
+            /// positive integer. Returns [`None`] if the result would be zero or negative.
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Self::new(self.get() - rhs.get())
+            }
+            /// Checked multiplication. Multiplies a strictly positive integer by another strictly
+            /// positive integer. Checks for overflow and returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.get().checked_mul(rhs.get()) {
+                    Some(n) => unsafe { Some(Self::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Divides a strictly positive integer by another strictly positive integer, returning
+            /// the positive quotient, which is zero if `self < rhs`.
+            ///
+            /// Since the divisor can never be zero and the quotient can never overflow, this
+            /// never panics.
+            #[inline]
+            pub const fn div_strictly_positive(self, rhs: Self) -> $pty {
+                unsafe { $pty::new_unchecked(self.get() / rhs.get()) }
+            }
+            /// Checked integer exponentiation. Raises a strictly positive value to an integer
+            /// power. Checks for overflow and returns [`None`] on overflow.
+            #[inline]
+            pub const fn checked_pow(self, rhs: u32) -> Option<Self> {
+                match self.get().checked_pow(rhs) {
+                    Some(n) => unsafe { Some(Self::new_unchecked(n)) },
+                    None => None,
+                }
+            }
+            /// Returns the smallest power of two greater than or equal to `self`.
+            /// Checks for overflow and returns [`None`]
+            /// if the next power of two is greater than the type’s maximum value.
+            #[inline]
+            pub const fn checked_next_power_of_two(self) -> Option<Self> {
+                Self::new((self.get() as $uns).next_power_of_two() as $base)
+            }
+            /// Saturating addition. Adds a strictly positive integer to another strictly positive
+            /// integer.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                let n = self.get().saturating_add(rhs.get());
+                unsafe { Self::new_unchecked(n) }
+            }
+            /// Saturating subtraction. Subtracts a strictly positive integer from another strictly
+            /// positive integer. Returns 1 if the result would be zero or negative.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match Self::new(self.get() - rhs.get()) {
+                    Some(n) => n,
+                    None => Self::MIN,
+                }
+            }
+            /// Saturating multiplication. Multiplies a strictly positive integer by another
+            /// strictly positive integer.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                let n = self.get().saturating_mul(rhs.get());
+                unsafe { Self::new_unchecked(n) }
+            }
+            /// Saturating integer exponentiation. Raises a strictly positive value to an integer
+            /// power.
+            #[doc = concat!("Returns [`", stringify!($ty), "::MAX`] on overflow.")]
+            #[inline]
+            pub const fn saturating_pow(self, rhs: u32) -> Self {
+                let n = self.get().saturating_pow(rhs);
+                unsafe { Self::new_unchecked(n) }
+            }
+        }
+
+        impl $pty {
+            /// Divides a positive integer by a strictly positive integer, returning the positive
+            /// quotient.
+            ///
+            /// Since the divisor can never be zero, this never panics.
+            #[inline]
+            pub const fn div_strictly_positive(self, rhs: $ty) -> Self {
+                unsafe { Self::new_unchecked(self.get() / rhs.get()) }
+            }
+            /// Divides a positive integer by a strictly positive integer, returning the positive
+            /// remainder.
+            ///
+            /// Since the divisor can never be zero, this never panics.
+            #[inline]
+            pub const fn rem_strictly_positive(self, rhs: $ty) -> Self {
+                unsafe { Self::new_unchecked(self.get() % rhs.get()) }
+            }
+        }
+
+        impl PartialEq for $ty {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.get().eq(&rhs.get())
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $ty {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&rhs.get())
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::str::FromStr for $ty {
+            type Err = core::num::IntErrorKind;
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let n = s.parse::<$uns>().map_err(|e| e.kind().clone())?;
+                if n == 0 {
+                    return Err(core::num::IntErrorKind::Zero);
+                }
+                Self::new(n as $base).ok_or_else(|| core::num::IntErrorKind::PosOverflow)
+            }
+        }
+
+        impl core::hash::Hash for $ty {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+
+        impl core::ops::Div<$ty> for $pty {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: $ty) -> Self::Output {
+                self.div_strictly_positive(rhs)
+            }
+        }
+        impl core::ops::DivAssign<$ty> for $pty {
+            #[inline]
+            fn div_assign(&mut self, rhs: $ty) {
+                *self = core::ops::Div::div(*self, rhs);
+            }
+        }
+
+        impl core::ops::Rem<$ty> for $pty {
+            type Output = Self;
+            #[inline]
+            fn rem(self, rhs: $ty) -> Self::Output {
+                self.rem_strictly_positive(rhs)
+            }
+        }
+        impl core::ops::RemAssign<$ty> for $pty {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $ty) {
+                *self = core::ops::Rem::rem(*self, rhs);
+            }
+        }
+
+        impl core::ops::BitOr<$pty> for $ty {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: $pty) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() | rhs.get()) }
+            }
+        }
+        impl core::ops::BitOrAssign<$pty> for $ty {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $pty) {
+                *self = core::ops::BitOr::bitor(*self, rhs);
+            }
+        }
+
+        impl From<$ty> for $pty {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.0
+            }
+        }
+        impl TryFrom<$pty> for $ty {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: $pty) -> Result<Self, Self::Error> {
+                Self::new(value.get()).ok_or_else(|| <$base>::try_from(<$uns>::MAX).unwrap_err())
+            }
+        }
+
+        impl From<$ty> for core::num::NonZero<$base> {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                unsafe { Self::new_unchecked(value.get()) }
+            }
+        }
+        impl TryFrom<core::num::NonZero<$base>> for $ty {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: core::num::NonZero<$base>) -> Result<Self, Self::Error> {
+                Self::new(value.get()).ok_or_else(|| <$base>::try_from(<$uns>::MAX).unwrap_err())
+            }
+        }
+
+        impl From<$ty> for core::num::NonZero<$uns> {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                unsafe { Self::new_unchecked(value.get() as $uns) }
+            }
+        }
+        impl TryFrom<core::num::NonZero<$uns>> for $ty {
+            type Error = core::num::TryFromIntError;
+            #[inline]
+            fn try_from(value: core::num::NonZero<$uns>) -> Result<Self, Self::Error> {
+                let value = <$base>::try_from(value.get())?;
+                unsafe { Ok(Self::new_unchecked(value)) }
+            }
+        }
+
+        impl_primitive_from! { $ty => $base, $uns }
+        impl_fmt! { Display, Debug, Binary, Octal, LowerHex, UpperHex => $ty }
+    };
+}
+
+macro_rules! impl_positive_float {
+    ($(#[$attr:meta])* $ty:ident, $nty:ident, $d:tt $mac:ident, $base:ident, $bits:ty) => {
+
+        #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
+        ///
+        #[doc = concat!("This macro takes a single argument that must be a positive [`", stringify!($base), "`].")]
+        /// Negative values, negative zero and NaN will cause a compile error.
+        ///
+        /// ```rust
+        #[doc = concat!("use sign_bound::{", stringify!($ty), ", ", stringify!($mac), "};")]
+        ///
+        #[doc = concat!("let val: ", stringify!($ty), " = ", stringify!($mac), "!(1.5);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $mac {
+            ($e:expr $d(,)?) => {
+                const {
+                    match $crate::$ty::new($e) {
+                        ::core::option::Option::Some(e) => e,
+                        _ => panic!(concat!(stringify!($base), " out of range for ", stringify!($ty))),
+                    }
+                }
+            };
+        }
+
+        /// A floating point value that is known to be positive and not NaN.
+        ///
+        /// Both positive zero and positive infinity are valid values, but negative zero is not.
+        ///
+        /// This enables some memory layout optimization.
+        #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($base), "`].")]
+        #[derive(Copy, Clone)]
+        #[repr(C)]
+        $(#[$attr])*
+        pub struct $ty {
+            #[cfg(target_endian = "big")]
+            _hi: PositiveHighByte,
+            _buf: [u8; size_of::<$base>() - 1],
+            #[cfg(target_endian = "little")]
+            _hi: PositiveHighByte,
+        }
+
+        impl $ty {
+            /// Positive zero, the smallest value that can be represented by this type.
+            pub const MIN: Self = unsafe { $ty::new_unchecked(0.0) };
+            #[doc = concat!("The largest finite value that can be represented by this type, equal to [`", stringify!($base), "::MAX`].")]
+            pub const MAX: Self = unsafe { $ty::new_unchecked(<$base>::MAX) };
+            /// Positive infinity.
+            pub const INFINITY: Self = unsafe { $ty::new_unchecked(<$base>::INFINITY) };
+            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is positive and not NaN.")]
+            ///
+            /// Returns [`None`] for negative zero.
+            pub const fn new(value: $base) -> Option<Self> {
+                if Self::bits(value) > Self::bits(<$base>::INFINITY) {
+                    return None;
+                }
+                unsafe { Some(core::mem::transmute::<$base, Self>(value)) }
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is positive.")]
+            /// This results in undefined behaviour if the value is negative, negative zero or NaN.
+            ///
+            /// # Safety
+            ///
+            /// The value must not be negative, negative zero or NaN.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $base) -> Self {
+                debug_assert!(Self::bits(value) <= Self::bits(<$base>::INFINITY));
+                core::mem::transmute::<$base, Self>(value)
+            }
+            /// Returns the contained value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $base {
+                unsafe { core::mem::transmute::<Self, $base>(self) }
+            }
+            /// Returns the raw binary representation of a primitive value. Unlike `to_bits`, this
+            /// is `const` on the minimum supported Rust version.
+            #[inline]
+            #[allow(unknown_lints, unnecessary_transmutes)]
+            const fn bits(value: $base) -> $bits {
+                unsafe { core::mem::transmute::<$base, $bits>(value) }
+            }
+            /// Returns the raw binary representation of `self`.
+            #[inline]
+            pub const fn to_bits(self) -> $bits {
+                unsafe {
+                    let n = core::mem::transmute::<Self, $bits>(self);
+                    core::hint::assert_unchecked(n <= Self::bits(<$base>::INFINITY));
+                    n
+                }
+            }
+            /// Returns a number representing the sign of `self`.
+            ///
+            /// Since the value is guaranteed to be positive, this function always returns `1.0`.
+            #[inline]
+            pub const fn signum(self) -> $base {
+                1.0
+            }
+            /// Returns `true` if this value is positive infinity.
+            #[inline]
+            pub const fn is_infinite(self) -> bool {
+                self.to_bits() == Self::bits(<$base>::INFINITY)
+            }
+            /// Returns `true` if this value is neither infinite nor NaN.
+            #[inline]
+            pub const fn is_finite(self) -> bool {
+                !self.is_infinite()
+            }
+            /// Checked subtraction. Subtracts a positive value from another positive value.
+            /// Returns [`None`] if the result would be negative or NaN.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Self::new(self.get() - rhs.get())
+            }
+            /// Checked multiplication.
+            /// Multiplies a positive value by another positive value, returning a positive result.
+            /// Returns [`None`] if the result is NaN, which happens when multiplying zero by
+            /// infinity.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Self::new(self.get() * rhs.get())
+            }
+            /// Checked division.
+            /// Divides a positive value by another positive value, returning a positive result.
+            /// Returns [`None`] if the result is NaN, which happens when dividing zero by zero or
+            /// infinity by infinity.
+            #[inline]
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                Self::new(self.get() / rhs.get())
+            }
+            /// Saturating subtraction. Subtracts a positive value from another positive value.
+            /// Returns positive zero if the result would be negative or NaN.
+            #[inline]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(n) => n,
+                    None => Self::MIN,
+                }
+            }
+            /// Returns the maximum of the two values.
+            #[inline]
+            pub fn max(self, rhs: Self) -> Self {
+                core::cmp::Ord::max(self, rhs)
+            }
+            /// Returns the minimum of the two values.
+            #[inline]
+            pub fn min(self, rhs: Self) -> Self {
+                core::cmp::Ord::min(self, rhs)
+            }
+        }
+
+        impl Default for $ty {
+            #[inline]
+            fn default() -> Self {
+                Self::MIN
+            }
+        }
+
+        impl PartialEq for $ty {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.to_bits().eq(&rhs.to_bits())
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $ty {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                self.to_bits().cmp(&rhs.to_bits())
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::hash::Hash for $ty {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state);
+            }
+        }
+
+        impl core::ops::Add for $ty {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() + rhs.get()) }
+            }
+        }
+        impl core::ops::AddAssign for $ty {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = core::ops::Add::add(*self, rhs);
+            }
+        }
+
+        impl core::ops::Sub<$nty> for $ty {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: $nty) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() - rhs.get()) }
+            }
+        }
+        impl core::ops::SubAssign<$nty> for $ty {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $nty) {
+                *self = core::ops::Sub::sub(*self, rhs);
+            }
+        }
+
+        impl core::ops::Neg for $ty {
+            type Output = $nty;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                unsafe { $nty::new_unchecked(-self.get()) }
+            }
+        }
+
+        impl From<$ty> for $base {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.get()
+            }
+        }
+
+        impl TryFrom<$base> for $ty {
+            type Error = TryFromFloatError;
+            #[inline]
+            fn try_from(value: $base) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(TryFromFloatError(()))
+            }
+        }
+
+        impl_fmt! { Display, Debug, LowerExp, UpperExp => $ty }
+    };
+}
+
+macro_rules! impl_negative_float {
+    ($(#[$attr:meta])* $ty:ident, $pty:ident, $d:tt $mac:ident, $base:ident, $bits:ty) => {
+
+        #[doc = concat!("Creates a [`", stringify!($ty), "`] checked at compile time.")]
+        ///
+        #[doc = concat!("This macro takes a single argument that must be a negative [`", stringify!($base), "`].")]
+        /// Positive values, positive zero and NaN will cause a compile error.
+        ///
+        /// ```rust
+        #[doc = concat!("use sign_bound::{", stringify!($ty), ", ", stringify!($mac), "};")]
+        ///
+        #[doc = concat!("let val: ", stringify!($ty), " = ", stringify!($mac), "!(-1.5);")]
+        /// ```
+        #[macro_export]
+        macro_rules! $mac {
+            ($e:expr $d(,)?) => {
+                const {
+                    match $crate::$ty::new($e) {
+                        ::core::option::Option::Some(e) => e,
+                        _ => panic!(concat!(stringify!($base), " out of range for ", stringify!($ty))),
+                    }
+                }
+            };
+        }
+
+        /// A floating point value that is known to be negative and not NaN.
+        ///
+        /// Both negative zero and negative infinity are valid values, but positive zero is not.
+        ///
+        /// This enables some memory layout optimization.
+        #[doc = concat!("For example, `Option<", stringify!($ty), ">` is the same size as [`", stringify!($base), "`].")]
+        #[derive(Copy, Clone)]
+        $(#[$attr])*
+        #[repr(C)]
+        pub struct $ty {
+            #[cfg(target_endian = "big")]
+            _hi: NegativeHighByte,
+            _buf: [u8; size_of::<$base>() - 1],
+            #[cfg(target_endian = "little")]
+            _hi: NegativeHighByte,
+        }
+
+        impl $ty {
+            #[doc = concat!("The smallest finite value that can be represented by this type, equal to [`", stringify!($base), "::MIN`].")]
+            pub const MIN: Self = unsafe { $ty::new_unchecked(<$base>::MIN) };
+            /// Negative zero, the largest value that can be represented by this type.
+            pub const MAX: Self = unsafe { $ty::new_unchecked(-0.0) };
+            /// Negative infinity.
+            pub const NEG_INFINITY: Self = unsafe { $ty::new_unchecked(<$base>::NEG_INFINITY) };
+            #[doc = concat!("Creates a `", stringify!($ty), "` if the given value is negative and not NaN.")]
+            ///
+            /// Returns [`None`] for positive zero.
+            pub const fn new(value: $base) -> Option<Self> {
+                let bits = Self::bits(value);
+                if bits < Self::bits(-0.0) || bits > Self::bits(<$base>::NEG_INFINITY) {
+                    return None;
+                }
+                unsafe { Some(core::mem::transmute::<$base, Self>(value)) }
+            }
+            #[doc = concat!("Creates a `", stringify!($ty), "` without checking whether the value is negative.")]
+            /// This results in undefined behaviour if the value is positive, positive zero or NaN.
+            ///
+            /// # Safety
+            ///
+            /// The value must not be positive, positive zero or NaN.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: $base) -> Self {
+                debug_assert!({
+                    let bits = Self::bits(value);
+                    bits >= Self::bits(-0.0) && bits <= Self::bits(<$base>::NEG_INFINITY)
+                });
+                core::mem::transmute::<$base, Self>(value)
+            }
+            /// Returns the contained value as a primitive type.
+            #[inline]
+            pub const fn get(self) -> $base {
+                unsafe { core::mem::transmute::<Self, $base>(self) }
+            }
+            /// Returns the raw binary representation of a primitive value. Unlike `to_bits`, this
+            /// is `const` on the minimum supported Rust version.
+            #[inline]
+            #[allow(unknown_lints, unnecessary_transmutes)]
+            const fn bits(value: $base) -> $bits {
+                unsafe { core::mem::transmute::<$base, $bits>(value) }
+            }
+            /// Returns the raw binary representation of `self`.
+            #[inline]
+            pub const fn to_bits(self) -> $bits {
+                unsafe {
+                    let n = core::mem::transmute::<Self, $bits>(self);
+                    core::hint::assert_unchecked(
+                        n >= Self::bits(-0.0) && n <= Self::bits(<$base>::NEG_INFINITY)
+                    );
+                    n
+                }
+            }
+            /// Returns a number representing the sign of `self`.
+            ///
+            /// Since the value is guaranteed to be negative, this function always returns `-1.0`.
+            #[inline]
+            pub const fn signum(self) -> $base {
+                -1.0
+            }
+            /// Returns `true` if this value is negative infinity.
+            #[inline]
+            pub const fn is_infinite(self) -> bool {
+                self.to_bits() == Self::bits(<$base>::NEG_INFINITY)
+            }
+            /// Returns `true` if this value is neither infinite nor NaN.
+            #[inline]
+            pub const fn is_finite(self) -> bool {
+                !self.is_infinite()
+            }
+            /// Checked subtraction. Subtracts a negative value from another negative value.
+            /// Returns [`None`] if the result would be positive or NaN.
+            #[inline]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Self::new(self.get() - rhs.get())
+            }
+            /// Checked multiplication.
+            /// Multiplies a negative value by another negative value, returning a positive result.
+            /// Returns [`None`] if the result is NaN, which happens when multiplying zero by
+            /// infinity.
+            #[inline]
+            pub fn checked_mul(self, rhs: Self) -> Option<$pty> {
+                $pty::new(self.get() * rhs.get())
+            }
+            /// Checked sign-preserving multiplication.
+            /// Multiplies a negative value by a positive value, returning a negative result.
+            /// Returns [`None`] if the result is NaN, which happens when multiplying zero by
+            /// infinity.
+            #[inline]
+            pub fn checked_mul_positive(self, rhs: $pty) -> Option<Self> {
+                Self::new(self.get() * rhs.get())
+            }
+            /// Checked division.
+            /// Divides a negative value by another negative value, returning a positive result.
+            /// Returns [`None`] if the result is NaN, which happens when dividing zero by zero or
+            /// infinity by infinity.
+            #[inline]
+            pub fn checked_div(self, rhs: Self) -> Option<$pty> {
+                $pty::new(self.get() / rhs.get())
+            }
+            /// Saturating subtraction. Subtracts a negative value from another negative value.
+            /// Returns negative zero if the result would be positive or NaN.
+            #[inline]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(n) => n,
+                    None => Self::MAX,
+                }
+            }
+            /// Returns the maximum of the two values.
+            #[inline]
+            pub fn max(self, rhs: Self) -> Self {
+                core::cmp::Ord::max(self, rhs)
+            }
+            /// Returns the minimum of the two values.
+            #[inline]
+            pub fn min(self, rhs: Self) -> Self {
+                core::cmp::Ord::min(self, rhs)
+            }
+        }
+
+        impl PartialEq for $ty {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.to_bits().eq(&rhs.to_bits())
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $ty {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                rhs.to_bits().cmp(&self.to_bits())
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::hash::Hash for $ty {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state);
+            }
+        }
+
+        impl core::ops::Add for $ty {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() + rhs.get()) }
+            }
+        }
+        impl core::ops::AddAssign for $ty {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = core::ops::Add::add(*self, rhs);
+            }
+        }
+
+        impl core::ops::Sub<$pty> for $ty {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: $pty) -> Self::Output {
+                unsafe { Self::new_unchecked(self.get() - rhs.get()) }
+            }
+        }
+        impl core::ops::SubAssign<$pty> for $ty {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $pty) {
+                *self = core::ops::Sub::sub(*self, rhs);
+            }
+        }
+
+        impl core::ops::Neg for $ty {
+            type Output = $pty;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                unsafe { $pty::new_unchecked(-self.get()) }
+            }
+        }
+
+        impl From<$ty> for $base {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                value.get()
+            }
+        }
+
+        impl TryFrom<$base> for $ty {
+            type Error = TryFromFloatError;
+            #[inline]
+            fn try_from(value: $base) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(TryFromFloatError(()))
+            }
+        }
+
+        impl_fmt! { Display, Debug, LowerExp, UpperExp => $ty }
+    };
+}
+
+macro_rules! impl_float_from {
+    (=> $ty:ty, $base:ty) => {};
+    ($from:ty $(, $rest:ty)* => $ty:ty, $base:ty) => {
+        impl From<$from> for $ty {
+            #[inline]
+            fn from(value: $from) -> Self {
+                unsafe { Self::new_unchecked(value.get() as $base) }
+            }
+        }
+        impl_float_from! { $($rest),* => $ty, $base }
+    };
+}
+
+macro_rules! impl_float_try_from_int {
+    ($($from:ident($ibase:ty)),* => $ty:ty, $base:ty) => {$(
+        impl TryFrom<$from> for $ty {
+            type Error = TryFromFloatError;
+            #[inline]
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                let f = value.get() as $base;
+                if f as $ibase == value.get() && f != -(<$ibase>::MIN as $base) {
+                    Ok(unsafe { Self::new_unchecked(f) })
+                } else {
+                    Err(TryFromFloatError(()))
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_int_try_from_float {
+    ($from:ty, $base:ty => $($ty:ident($ibase:ty)),*) => {$(
+        impl TryFrom<$from> for $ty {
+            type Error = TryFromFloatError;
+            #[inline]
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                let n = value.get() as $ibase;
+                if n as $base == value.get() && value.get() != -(<$ibase>::MIN as $base) {
+                    $ty::new(n).ok_or(TryFromFloatError(()))
+                } else {
+                    Err(TryFromFloatError(()))
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float_try_from_float {
+    ($($from:ty),* => $ty:ty, $base:ty) => {$(
+        impl TryFrom<$from> for $ty {
+            type Error = TryFromFloatError;
+            #[inline]
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                let value = f64::from(value);
+                let f = value as $base;
+                if f as f64 == value {
+                    Self::new(f).ok_or(TryFromFloatError(()))
+                } else {
+                    Err(TryFromFloatError(()))
+                }
+            }
+        }
+    )*};
+}
+
+/// A primitive signed integer type that can be bounded by [`Positive`] or [`Negative`].
+///
+/// This trait is sealed and implemented for [`i8`], [`i16`], [`i32`], [`i64`], [`i128`] and
+/// [`isize`].
+pub trait SignBoundInt:
+    private::Sealed
+    + Copy
+    + Default
+    + Ord
+    + core::hash::Hash
+    + core::fmt::Debug
+    + core::fmt::Display
+    + core::fmt::Binary
+    + core::fmt::Octal
+    + core::fmt::LowerHex
+    + core::fmt::UpperHex
+{
+}
+
+/// A primitive integer type that can be converted into any sign-bound type by clamping.
+///
+/// This trait is sealed and implemented for all primitive integer types.
+///
+/// ```
+/// # use sign_bound::{NegativeI16, PositiveI8};
+/// assert_eq!(PositiveI8::saturating_from(-5i64).get(), 0);
+/// assert_eq!(PositiveI8::saturating_from(300u16), PositiveI8::MAX);
+/// assert_eq!(NegativeI16::saturating_from(7u32).get(), -1);
+/// ```
+pub trait PrimInt: private::PrimSealed + Copy {}
+
+macro_rules! impl_prim_int {
+    ($($signed:ty),* ; $($unsigned:ty),*) => {
+        $(
+            impl private::PrimSealed for $signed {
+                #[inline]
+                fn widen(self) -> private::Wide {
+                    private::Wide::Signed(self as i128)
+                }
+            }
+            impl PrimInt for $signed {}
+        )*
+        $(
+            impl private::PrimSealed for $unsigned {
+                #[inline]
+                fn widen(self) -> private::Wide {
+                    private::Wide::Unsigned(self as u128)
+                }
+            }
+            impl PrimInt for $unsigned {}
+        )*
+    };
+}
+
+impl_prim_int! { i8, i16, i32, i64, i128, isize; u8, u16, u32, u64, u128, usize }
+
+mod private {
+    pub trait Sealed {
+        type Buf: Copy;
+        const ZERO: Self;
+        const NEG_ONE: Self;
+        const MIN: Self;
+        const MAX: Self;
+    }
+
+    pub enum Wide {
+        Signed(i128),
+        Unsigned(u128),
+    }
+
+    pub trait PrimSealed {
+        fn widen(self) -> Wide;
+    }
+
+    pub trait IteratorSealed<T> {}
+}
+
+/// Reinterprets the bits of `value` as a value of type `B`.
+///
+/// Unlike [`transmute`](core::mem::transmute), this can be used on generic types in a `const fn`.
+///
+/// # Safety
+///
+/// `A` and `B` must have the same size, and the bits of `value` must be valid for `B`.
+#[inline]
+const unsafe fn transmute_generic<A: Copy, B: Copy>(value: A) -> B {
+    #[repr(C)]
+    union Transmute<A: Copy, B: Copy> {
+        a: A,
+        b: B,
+    }
+    Transmute { a: value }.b
+}
+
+/// The layout of a primitive signed integer, with the byte holding the sign bit split out.
+#[derive(Copy, Clone)]
+#[repr(C)]
+struct HighByte<T: SignBoundInt> {
+    _align: [T; 0],
+    #[cfg(target_endian = "big")]
+    hi: u8,
+    _buf: T::Buf,
+    #[cfg(target_endian = "little")]
+    hi: u8,
+}
+
+/// Returns `true` if the sign bit of `value` is set.
+#[inline]
+const fn is_sign_negative<T: SignBoundInt>(value: T) -> bool {
+    unsafe { transmute_generic::<T, HighByte<T>>(value).hi >= 0x80 }
+}
+
+/// Multiplies two `u128` values, returning the low and high halves of the 256-bit product.
+#[inline]
+const fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a0, a1) = (a & MASK, a >> 64);
+    let (b0, b1) = (b & MASK, b >> 64);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (lo, hi)
+}
+
+/// Negates a 256-bit two's complement value given as low and high halves.
+#[inline]
+const fn negate_u256(lo: u128, hi: u128) -> (u128, u128) {
+    let lo = (!lo).wrapping_add(1);
+    let hi = (!hi).wrapping_add((lo == 0) as u128);
+    (lo, hi)
+}
+
+/// A signed value that is known to be positive.
+///
+/// This enables some memory layout optimization.
+/// For example, `Option<Positive<i32>>` is the same size as [`i32`].
+///
+/// Type aliases are provided for each primitive type, such as [`PositiveI32`].
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct Positive<T: SignBoundInt> {
+    _align: [T; 0],
+    #[cfg(target_endian = "big")]
+    _hi: PositiveHighByte,
+    _buf: T::Buf,
+    #[cfg(target_endian = "little")]
+    _hi: PositiveHighByte,
+}
+
+impl<T: SignBoundInt> Positive<T> {
+    /// The size of this positive integer type in bits.
+    ///
+    /// This value is equal to the `BITS` constant of the primitive type.
+    pub const BITS: u32 = size_of::<T>() as u32 * 8;
+    /// The smallest value that can be represented by this positive integer type, 0.
+    pub const MIN: Self = unsafe { Self::new_unchecked(T::ZERO) };
+    /// The largest value that can be represented by this positive integer type, equal to the
+    /// `MAX` constant of the primitive type.
+    pub const MAX: Self = unsafe { Self::new_unchecked(T::MAX) };
+    /// Creates a `Positive` if the given value is positive.
+    pub const fn new(value: T) -> Option<Self> {
+        if is_sign_negative(value) {
+            return None;
+        }
+        unsafe { Some(transmute_generic::<T, Self>(value)) }
+    }
+    /// Creates a `Positive` without checking whether the value is positive.
+    /// This results in undefined behaviour if the value is negative.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be negative.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: T) -> Self {
+        debug_assert!(!is_sign_negative(value));
+        transmute_generic::<T, Self>(value)
+    }
+    /// Returns the contained value as a primitive type.
+    #[inline]
+    pub const fn get(self) -> T {
+        unsafe {
+            let value = transmute_generic::<Self, T>(self);
+            core::hint::assert_unchecked(!is_sign_negative(value));
+            value
+        }
+    }
+}
+
+impl<T: SignBoundInt> Default for Positive<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+/// A signed value that is known to be negative.
+///
+/// This enables some memory layout optimization.
+/// For example, `Option<Negative<i32>>` is the same size as [`i32`].
+///
+/// Type aliases are provided for each primitive type, such as [`NegativeI32`].
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct Negative<T: SignBoundInt> {
+    _align: [T; 0],
+    #[cfg(target_endian = "big")]
+    _hi: NegativeHighByte,
+    _buf: T::Buf,
+    #[cfg(target_endian = "little")]
+    _hi: NegativeHighByte,
+}
+
+impl<T: SignBoundInt> Negative<T> {
+    /// The size of this negative integer type in bits.
+    ///
+    /// This value is equal to the `BITS` constant of the primitive type.
+    pub const BITS: u32 = size_of::<T>() as u32 * 8;
+    /// The smallest value that can be represented by this negative integer type, equal to the
+    /// `MIN` constant of the primitive type.
+    pub const MIN: Self = unsafe { Self::new_unchecked(T::MIN) };
+    /// The largest value that can be represented by this negative integer type, -1.
+    pub const MAX: Self = unsafe { Self::new_unchecked(T::NEG_ONE) };
+    /// Creates a `Negative` if the given value is negative.
+    pub const fn new(value: T) -> Option<Self> {
+        if !is_sign_negative(value) {
+            return None;
+        }
+        unsafe { Some(transmute_generic::<T, Self>(value)) }
+    }
+    /// Creates a `Negative` without checking whether the value is negative.
+    /// This results in undefined behaviour if the value is positive.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be positive.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: T) -> Self {
+        debug_assert!(is_sign_negative(value));
+        transmute_generic::<T, Self>(value)
+    }
+    /// Returns the contained value as a primitive type.
+    #[inline]
+    pub const fn get(self) -> T {
+        unsafe {
+            let value = transmute_generic::<Self, T>(self);
+            core::hint::assert_unchecked(is_sign_negative(value));
+            value
+        }
+    }
+}
+
+macro_rules! impl_sign_bound {
+    ($($ty:ident),*) => {$(
+        impl<T: SignBoundInt> PartialEq for $ty<T> {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.get().eq(&rhs.get())
+            }
+        }
+
+        impl<T: SignBoundInt> PartialOrd for $ty<T> {
+            fn partial_cmp(&self, rhs: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl<T: SignBoundInt> Ord for $ty<T> {
+            fn cmp(&self, rhs: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&rhs.get())
+            }
+        }
+
+        impl<T: SignBoundInt> Eq for $ty<T> {}
+
+        impl<T: SignBoundInt> core::hash::Hash for $ty<T> {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+
+        impl_fmt! { Display, Debug, Binary, Octal, LowerHex, UpperHex => $ty<T> where [T: SignBoundInt] }
+    )*};
+}
+
+impl_sign_bound! { Positive, Negative }
+
+/// A value that is either positive or negative.
+///
+/// This is returned by [`SignEither::classify`] and is intended to be used in `match`
+/// expressions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Either<P, N> {
+    /// A positive value.
+    Positive(P),
+    /// A negative value.
+    Negative(N),
+}
+
+impl<P, N> Either<P, N> {
+    /// Returns `true` if this is a [`Positive`](Either::Positive) value.
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        matches!(self, Self::Positive(_))
+    }
+    /// Returns `true` if this is a [`Negative`](Either::Negative) value.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        matches!(self, Self::Negative(_))
+    }
+    /// Returns the positive value, or [`None`] if the value is negative.
+    #[inline]
+    pub fn positive(self) -> Option<P> {
+        match self {
+            Self::Positive(p) => Some(p),
+            Self::Negative(_) => None,
+        }
+    }
+    /// Returns the negative value, or [`None`] if the value is positive.
+    #[inline]
+    pub fn negative(self) -> Option<N> {
+        match self {
+            Self::Positive(_) => None,
+            Self::Negative(n) => Some(n),
+        }
+    }
+}
+
+/// A signed value that is either positive or negative, using the sign bit as a tag.
+///
+/// This is useful for packing two kinds of values into a single integer, for example an index
+/// or an error code, or a leaf or an internal node. It is always the same size as the primitive
+/// type.
+///
+/// Type aliases are provided for each primitive type, such as [`SignEitherI32`].
+///
+/// ```rust
+/// # use sign_bound::{positive_i32, Either, SignEitherI32};
+/// let value = SignEitherI32::from_positive(positive_i32!(5));
+/// match value.classify() {
+///     Either::Positive(index) => assert_eq!(index.get(), 5),
+///     Either::Negative(_) => unreachable!(),
+/// }
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct SignEither<T: SignBoundInt>(T);
+
+impl<T: SignBoundInt> SignEither<T> {
+    /// Creates a `SignEither` from a primitive value.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+    /// Creates a `SignEither` holding a positive value.
+    #[inline]
+    pub const fn from_positive(value: Positive<T>) -> Self {
+        Self(value.get())
+    }
+    /// Creates a `SignEither` holding a negative value.
+    #[inline]
+    pub const fn from_negative(value: Negative<T>) -> Self {
+        Self(value.get())
+    }
+    /// Returns the contained value as a primitive type.
+    #[inline]
+    pub const fn get(self) -> T {
+        self.0
+    }
+    /// Returns `true` if the contained value is positive.
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        !is_sign_negative(self.0)
+    }
+    /// Returns `true` if the contained value is negative.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        is_sign_negative(self.0)
+    }
+    /// Returns the sign of the contained value.
+    #[inline]
+    pub const fn sign(self) -> Sign {
+        Sign::classify(self.0)
+    }
+    /// Splits the contained value into its positive or negative half.
+    #[inline]
+    pub const fn classify(self) -> Either<Positive<T>, Negative<T>> {
+        unsafe {
+            if is_sign_negative(self.0) {
+                Either::Negative(Negative::new_unchecked(self.0))
+            } else {
+                Either::Positive(Positive::new_unchecked(self.0))
+            }
+        }
+    }
+    /// Returns the contained value if it is positive, or [`None`] if it is negative.
+    #[inline]
+    pub const fn positive(self) -> Option<Positive<T>> {
+        Positive::new(self.0)
+    }
+    /// Returns the contained value if it is negative, or [`None`] if it is positive.
+    #[inline]
+    pub const fn negative(self) -> Option<Negative<T>> {
+        Negative::new(self.0)
+    }
+    /// Maps a positive value with `f`, leaving a negative value untouched.
+    #[inline]
+    pub fn map_positive<F: FnOnce(Positive<T>) -> Positive<T>>(self, f: F) -> Self {
+        match self.classify() {
+            Either::Positive(p) => Self::from_positive(f(p)),
+            Either::Negative(_) => self,
+        }
+    }
+    /// Maps a negative value with `f`, leaving a positive value untouched.
+    #[inline]
+    pub fn map_negative<F: FnOnce(Negative<T>) -> Negative<T>>(self, f: F) -> Self {
+        match self.classify() {
+            Either::Positive(_) => self,
+            Either::Negative(n) => Self::from_negative(f(n)),
+        }
+    }
+    /// Calls `positive` or `negative` depending on the sign of the contained value, and returns
+    /// the result.
+    #[inline]
+    pub fn either<U, P, N>(self, positive: P, negative: N) -> U
+    where
+        P: FnOnce(Positive<T>) -> U,
+        N: FnOnce(Negative<T>) -> U,
+    {
+        match self.classify() {
+            Either::Positive(p) => positive(p),
+            Either::Negative(n) => negative(n),
+        }
+    }
+}
+
+impl<T: SignBoundInt> From<T> for SignEither<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: SignBoundInt> From<Positive<T>> for SignEither<T> {
+    #[inline]
+    fn from(value: Positive<T>) -> Self {
+        Self::from_positive(value)
+    }
+}
+
+impl<T: SignBoundInt> From<Negative<T>> for SignEither<T> {
+    #[inline]
+    fn from(value: Negative<T>) -> Self {
+        Self::from_negative(value)
+    }
+}
+
+impl<T: SignBoundInt> From<Either<Positive<T>, Negative<T>>> for SignEither<T> {
+    #[inline]
+    fn from(value: Either<Positive<T>, Negative<T>>) -> Self {
+        match value {
+            Either::Positive(p) => Self::from_positive(p),
+            Either::Negative(n) => Self::from_negative(n),
+        }
+    }
+}
+
+impl<T: SignBoundInt> From<SignEither<T>> for Either<Positive<T>, Negative<T>> {
+    #[inline]
+    fn from(value: SignEither<T>) -> Self {
+        value.classify()
+    }
+}
+
+impl<T: SignBoundInt> core::fmt::Debug for SignEither<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.classify(), f)
+    }
+}
+
+impl_fmt! { Display, Binary, Octal, LowerHex, UpperHex => SignEither<T> where [T: SignBoundInt] }
+
+/// The sign of a signed value, as determined by its sign bit.
+///
+/// Zero is considered non-negative.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// The value is zero or greater.
+    NonNegative,
+    /// The value is less than zero.
+    Negative,
+}
+
+impl Sign {
+    /// Returns the sign of a primitive value.
+    ///
+    /// This only tests the sign bit.
+    #[inline]
+    pub const fn classify<T: SignBoundInt>(value: T) -> Self {
+        if is_sign_negative(value) {
+            Self::Negative
+        } else {
+            Self::NonNegative
+        }
+    }
+}
+
+/// A primitive value split into its non-negative or negative half.
+///
+/// This is returned by [`split_sign`] and is intended to be used in `match` expressions.
+///
+/// ```rust
+/// # use sign_bound::{split_sign, SignSplit};
+/// match split_sign(-5i32) {
+///     SignSplit::NonNegative(_) => unreachable!(),
+///     SignSplit::Negative(n) => assert_eq!(n.checked_abs().unwrap().get(), 5),
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SignSplit<T: SignBoundInt> {
+    /// A value that is zero or greater.
+    NonNegative(Positive<T>),
+    /// A value that is less than zero.
+    Negative(Negative<T>),
+}
+
+impl<T: SignBoundInt> SignSplit<T> {
+    /// Returns the sign of the contained value.
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        match self {
+            Self::NonNegative(_) => Sign::NonNegative,
+            Self::Negative(_) => Sign::Negative,
+        }
+    }
+    /// Returns the contained value as a primitive type.
+    #[inline]
+    pub const fn get(self) -> T {
+        match self {
+            Self::NonNegative(p) => p.get(),
+            Self::Negative(n) => n.get(),
+        }
+    }
+}
+
+impl<T: SignBoundInt> From<SignSplit<T>> for Either<Positive<T>, Negative<T>> {
+    #[inline]
+    fn from(value: SignSplit<T>) -> Self {
+        match value {
+            SignSplit::NonNegative(p) => Either::Positive(p),
+            SignSplit::Negative(n) => Either::Negative(n),
+        }
+    }
+}
+
+impl<T: SignBoundInt> From<Either<Positive<T>, Negative<T>>> for SignSplit<T> {
+    #[inline]
+    fn from(value: Either<Positive<T>, Negative<T>>) -> Self {
+        match value {
+            Either::Positive(p) => Self::NonNegative(p),
+            Either::Negative(n) => Self::Negative(n),
+        }
+    }
+}
+
+/// Splits a primitive value into its non-negative or negative half.
+///
+/// This only tests the sign bit once, and can be used in a `match` without any `unwrap`.
+#[inline]
+pub const fn split_sign<T: SignBoundInt>(value: T) -> SignSplit<T> {
+    unsafe {
+        match Sign::classify(value) {
+            Sign::NonNegative => SignSplit::NonNegative(Positive::new_unchecked(value)),
+            Sign::Negative => SignSplit::Negative(Negative::new_unchecked(value)),
+        }
+    }
+}
+
+/// Overflow-aware sums and products over iterators of positive integers.
+///
+/// This trait is sealed and implemented for every iterator over one of the positive integer
+/// types. Unlike [`Sum`](core::iter::Sum) and [`Product`](core::iter::Product), these never
+/// panic. Negative types are not covered, since neither the sum of an empty iterator nor a
+/// product of an even number of negative values is negative.
+///
+/// ```
+/// # use sign_bound::{PositiveI8, SignBoundIterator};
+/// let values = [PositiveI8::MAX, PositiveI8::MAX];
+/// assert_eq!(values.into_iter().checked_sum(), None);
+/// assert_eq!(values.into_iter().saturating_sum(), PositiveI8::MAX);
+/// assert_eq!(values.into_iter().wide_sum().get(), 254);
+/// ```
+pub trait SignBoundIterator<T>: private::IteratorSealed<T> + Iterator<Item = T> + Sized {
+    /// The type that [`wide_sum`](Self::wide_sum) accumulates into.
+    ///
+    /// This is the next larger positive type, such as [`PositiveI16`] for [`PositiveI8`]. There
+    /// is no larger type for [`PositiveI128`], so for it this is `(u128, PositiveI128)`, the low
+    /// and high halves of a 256-bit sum.
+    type Wide;
+    /// Sums the elements, returning [`None`] if the sum would overflow.
+    fn checked_sum(self) -> Option<T>;
+    /// Sums the elements, saturating at the maximum value on overflow.
+    fn saturating_sum(self) -> T;
+    /// Multiplies the elements, returning [`None`] if the product would overflow.
+    fn checked_product(self) -> Option<T>;
+    /// Sums the elements into [`Wide`](Self::Wide), saturating at its maximum value if even the
+    /// wider sum would overflow.
+    fn wide_sum(self) -> Self::Wide;
+}
+
+impl_positive! { PositiveI8, NegativeI8, $ positive_i8, i8, u8 }
+impl_sign_bound_iterator! { PositiveI8 => PositiveI16 }
+impl_from_get! { PositiveI8 => PositiveI16, PositiveI32, PositiveI64, PositiveI128, PositiveIsize }
+impl_primitive_from! { PositiveI8 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+impl_positive_try_from! { u8, u16, u32, u64, u128, usize => PositiveI8, i8 }
+impl_positive_try_from! { i16, i32, i64, i128, isize => PositiveI8, u8, i8 }
+impl_positive_try_from! { i8 => PositiveI8, u8 }
+impl_negative! { NegativeI8, PositiveI8, NonPositiveI8, $ negative_i8, i8, u8 }
+impl_from_get! { NegativeI8 => NegativeI16, NegativeI32, NegativeI64, NegativeI128, NegativeIsize }
+impl_primitive_from! { NegativeI8 => i8, i16, i32, i64, i128, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI8, u8, i8 }
+/// A signed value that is either positive or negative.
+pub type SignEitherI8 = SignEither<i8>;
+impl_primitive_from! { SignEitherI8 => i8 }
+impl_non_positive! { #[repr(align(1))] NonPositiveI8, NegativeI8, PositiveI8, $ non_positive_i8, i8, u8 }
+impl_from_get! { NonPositiveI8 => NonPositiveI16, NonPositiveI32, NonPositiveI64, NonPositiveI128, NonPositiveIsize }
+impl_primitive_from! { NonPositiveI8 => i8, i16, i32, i64, i128, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NonPositiveI8, u8, i8 }
+impl_widening_mul! { PositiveI8, NegativeI8 => PositiveI16, NonPositiveI16, i16 }
+
+impl_positive! { PositiveI16, NegativeI16, $ positive_i16, i16, u16 }
+impl_sign_bound_iterator! { PositiveI16 => PositiveI32 }
+impl_from! { u8 => PositiveI16 }
+impl_from_get! { PositiveI16 => PositiveI32, PositiveI64, PositiveI128, PositiveIsize }
+impl_primitive_from! { PositiveI16 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize }
+impl_primitive_try_from! { PositiveI16 => u8, i8 }
+impl_positive_try_from! { u16, u32, u64, u128, usize => PositiveI16, i16 }
+impl_positive_try_from! { i8, i32, i64, i128, isize => PositiveI16, u16, i16 }
+impl_positive_try_from! { i16 => PositiveI16, u16 }
+impl_negative! { NegativeI16, PositiveI16, NonPositiveI16, $ negative_i16, i16, u16 }
+impl_from_get! { NegativeI16 => NegativeI32, NegativeI64, NegativeI128, NegativeIsize }
+impl_primitive_from! { NegativeI16 => i16, i32, i64, i128, isize }
+impl_primitive_try_from! { NegativeI16 => i8 }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI16, u16, i16 }
+/// A signed value that is either positive or negative.
+pub type SignEitherI16 = SignEither<i16>;
+impl_primitive_from! { SignEitherI16 => i16 }
+impl_non_positive! { #[repr(align(2))] NonPositiveI16, NegativeI16, PositiveI16, $ non_positive_i16, i16, u16 }
+impl_from_get! { NonPositiveI16 => NonPositiveI32, NonPositiveI64, NonPositiveI128, NonPositiveIsize }
+impl_primitive_from! { NonPositiveI16 => i16, i32, i64, i128, isize }
+impl_primitive_try_from! { NonPositiveI16 => i8 }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NonPositiveI16, u16, i16 }
+impl_widening_mul! { PositiveI16, NegativeI16 => PositiveI32, NonPositiveI32, i32 }
+
+impl_positive! { PositiveI32, NegativeI32, $ positive_i32, i32, u32 }
+impl_sign_bound_iterator! { PositiveI32 => PositiveI64 }
+impl_from! { u8, u16 => PositiveI32 }
+impl_from_get! { PositiveI32 => PositiveI64, PositiveI128 }
+impl_primitive_from! { PositiveI32 => u32, u64, u128, i32, i64, i128 }
+impl_primitive_try_from! { PositiveI32 => u8, u16, usize, i8, i16, isize }
+impl_positive_try_from! { u32, u64, u128, usize => PositiveI32, i32 }
+impl_positive_try_from! { i8, i16, i64, i128, isize => PositiveI32, u32, i32 }
+impl_positive_try_from! { i32 => PositiveI32, u32 }
+impl_negative! { NegativeI32, PositiveI32, NonPositiveI32, $ negative_i32, i32, u32 }
+impl_from_get! { NegativeI32 => NegativeI64, NegativeI128 }
+impl_primitive_from! { NegativeI32 => i32, i64, i128 }
+impl_primitive_try_from! { NegativeI32 => i8, i16, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI32, u32, i32 }
+/// A signed value that is either positive or negative.
+pub type SignEitherI32 = SignEither<i32>;
+impl_primitive_from! { SignEitherI32 => i32 }
+impl_non_positive! { #[repr(align(4))] NonPositiveI32, NegativeI32, PositiveI32, $ non_positive_i32, i32, u32 }
+impl_from_get! { NonPositiveI32 => NonPositiveI64, NonPositiveI128 }
+impl_primitive_from! { NonPositiveI32 => i32, i64, i128 }
+impl_primitive_try_from! { NonPositiveI32 => i8, i16, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NonPositiveI32, u32, i32 }
+impl_widening_mul! { PositiveI32, NegativeI32 => PositiveI64, NonPositiveI64, i64 }
+
+impl_positive! { PositiveI64, NegativeI64, $ positive_i64, i64, u64 }
+impl_sign_bound_iterator! { PositiveI64 => PositiveI128 }
+impl_from! { u8, u16, u32 => PositiveI64 }
+impl_from_get! { PositiveI64 => PositiveI128 }
+impl_primitive_from! { PositiveI64 => u64, u128, i64, i128 }
+impl_primitive_try_from! { PositiveI64 => u8, u16, u32, usize, i8, i16, i32, isize }
+impl_positive_try_from! { u64, u128, usize => PositiveI64, i64 }
+impl_positive_try_from! { i8, i16, i32, i128, isize => PositiveI64, u64, i64 }
+impl_positive_try_from! { i64 => PositiveI64, u64 }
+impl_negative! { NegativeI64, PositiveI64, NonPositiveI64, $ negative_i64, i64, u64 }
+impl_from_get! { NegativeI64 => NegativeI128 }
+impl_primitive_from! { NegativeI64 => i64, i128 }
+impl_primitive_try_from! { NegativeI64 => i8, i16, i32, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI64, u64, i64 }
+/// A signed value that is either positive or negative.
+pub type SignEitherI64 = SignEither<i64>;
+impl_primitive_from! { SignEitherI64 => i64 }
+impl_non_positive! { #[repr(align(8))] NonPositiveI64, NegativeI64, PositiveI64, $ non_positive_i64, i64, u64 }
+impl_from_get! { NonPositiveI64 => NonPositiveI128 }
+impl_primitive_from! { NonPositiveI64 => i64, i128 }
+impl_primitive_try_from! { NonPositiveI64 => i8, i16, i32, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NonPositiveI64, u64, i64 }
+impl_widening_mul! { PositiveI64, NegativeI64 => PositiveI128, NonPositiveI128, i128 }
+
+impl_positive! { PositiveI128, NegativeI128, $ positive_i128, i128, u128 }
+impl_sign_bound_iterator! { PositiveI128 => (u128, PositiveI128), wide_sum(iter) {
+    iter.fold((0, PositiveI128::MIN), |(lo, hi), n| {
+        let (lo, carry) = lo.overflowing_add(n.get() as u128);
+        (lo, hi.saturating_add(PositiveI128::from(carry as u8)))
+    })
+} }
+impl_from! { u8, u16, u32, u64 => PositiveI128 }
+impl_primitive_from! { PositiveI128 => u128, i128 }
+impl_primitive_try_from! { PositiveI128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_positive_try_from! { u128, usize => PositiveI128, i128 }
+impl_positive_try_from! { i8, i16, i32, i64, isize => PositiveI128, u128, i128 }
+impl_positive_try_from! { i128 => PositiveI128, u128 }
+impl_negative! { NegativeI128, PositiveI128, NonPositiveI128, $ negative_i128, i128, u128 }
+impl_primitive_from! { NegativeI128 => i128 }
+impl_primitive_try_from! { NegativeI128 => i8, i16, i32, i64, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeI128, u128, i128 }
+/// A signed value that is either positive or negative.
+pub type SignEitherI128 = SignEither<i128>;
+impl_primitive_from! { SignEitherI128 => i128 }
+impl_non_positive! { #[repr(align(16))] NonPositiveI128, NegativeI128, PositiveI128, $ non_positive_i128, i128, u128 }
+impl_primitive_from! { NonPositiveI128 => i128 }
+impl_primitive_try_from! { NonPositiveI128 => i8, i16, i32, i64, isize }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NonPositiveI128, u128, i128 }
+
+impl PositiveI128 {
+    /// Widening multiplication. Computes `self * rhs`, returning the low and high halves of the
+    /// 256-bit product.
+    ///
+    /// This can never overflow.
+    #[inline]
+    pub const fn widening_mul(self, rhs: Self) -> (u128, PositiveI128) {
+        let (lo, hi) = widening_mul_u128(self.get() as u128, rhs.get() as u128);
+        unsafe { (lo, PositiveI128::new_unchecked(hi as i128)) }
+    }
+    /// Calculates `self * rhs + carry`, returning the low and high halves of the 256-bit
+    /// result.
+    ///
+    /// This can never overflow.
+    #[inline]
+    pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (u128, PositiveI128) {
+        let (lo, hi) = widening_mul_u128(self.get() as u128, rhs.get() as u128);
+        let (lo, c) = lo.overflowing_add(carry.get() as u128);
+        unsafe { (lo, PositiveI128::new_unchecked((hi + c as u128) as i128)) }
+    }
+    /// Widening multiplication. Computes `self * rhs`, returning the low and high halves of the
+    /// 256-bit two's complement product.
+    ///
+    /// This can never overflow.
+    #[inline]
+    pub const fn widening_mul_negative(self, rhs: NegativeI128) -> (u128, NonPositiveI128) {
+        let (lo, hi) = widening_mul_u128(self.get() as u128, rhs.unsigned_abs());
+        let (lo, hi) = negate_u256(lo, hi);
+        unsafe { (lo, NonPositiveI128::new_unchecked(hi as i128)) }
+    }
+}
+
+impl NegativeI128 {
+    /// Widening multiplication. Computes `self * rhs`, returning the low and high halves of the
+    /// 256-bit product.
+    ///
+    /// This can never overflow.
+    #[inline]
+    pub const fn widening_mul(self, rhs: Self) -> (u128, PositiveI128) {
+        let (lo, hi) = widening_mul_u128(self.unsigned_abs(), rhs.unsigned_abs());
+        unsafe { (lo, PositiveI128::new_unchecked(hi as i128)) }
+    }
+    /// Widening multiplication. Computes `self * rhs`, returning the low and high halves of the
+    /// 256-bit two's complement product.
+    ///
+    /// This can never overflow.
+    #[inline]
+    pub const fn widening_mul_positive(self, rhs: PositiveI128) -> (u128, NonPositiveI128) {
+        rhs.widening_mul_negative(self)
+    }
+}
+
+#[cfg(not(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+)))]
+compile_error!("unsupported pointer width");
+
+impl_positive! { PositiveIsize, NegativeIsize, $ positive_isize, isize, usize }
+impl_sign_bound_iterator! { PositiveIsize => PositiveI128 }
+impl_from! { u8 => PositiveIsize }
+impl_try_from! { PositiveIsize => PositiveI32, PositiveI64 }
+impl_from_get! { PositiveIsize => PositiveI128 }
+impl_primitive_from! { PositiveIsize => usize, isize }
+impl_primitive_try_from! { PositiveIsize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128 }
+impl_positive_try_from! { u16, u32, u64, u128, usize => PositiveIsize, isize }
+impl_positive_try_from! { i8, i16, i32, i64, i128 => PositiveIsize, usize, isize }
+impl_positive_try_from! { isize => PositiveIsize, usize }
+impl_negative! { NegativeIsize, PositiveIsize, NonPositiveIsize, $ negative_isize, isize, usize }
+impl_try_from! { NegativeIsize => NegativeI32, NegativeI64 }
+impl_from_get! { NegativeIsize => NegativeI128 }
+impl_primitive_from! { NegativeIsize => isize }
+impl_primitive_try_from! { NegativeIsize => i8, i16, i32, i64, i128 }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NegativeIsize, usize, isize }
+/// A signed value that is either positive or negative.
+pub type SignEitherIsize = SignEither<isize>;
+impl_primitive_from! { SignEitherIsize => isize }
+impl_non_positive! {
+    #[cfg_attr(target_pointer_width = "16", repr(align(2)))]
+    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+    NonPositiveIsize, NegativeIsize, PositiveIsize, $ non_positive_isize, isize, usize
+}
+impl_try_from! { NonPositiveIsize => NonPositiveI32, NonPositiveI64 }
+impl_from_get! { NonPositiveIsize => NonPositiveI128 }
+impl_primitive_from! { NonPositiveIsize => isize }
+impl_primitive_try_from! { NonPositiveIsize => i8, i16, i32, i64, i128 }
+impl_negative_try_from! { i8, i16, i32, i64, i128, isize => NonPositiveIsize, usize, isize }
+
+impl_strictly_positive! { StrictlyPositiveI8, PositiveI8, $ strictly_positive_i8, i8, u8 }
+impl_strictly_positive! { StrictlyPositiveI16, PositiveI16, $ strictly_positive_i16, i16, u16 }
+impl_strictly_positive! { StrictlyPositiveI32, PositiveI32, $ strictly_positive_i32, i32, u32 }
+impl_strictly_positive! { StrictlyPositiveI64, PositiveI64, $ strictly_positive_i64, i64, u64 }
+impl_strictly_positive! { StrictlyPositiveI128, PositiveI128, $ strictly_positive_i128, i128, u128 }
+impl_strictly_positive! { StrictlyPositiveIsize, PositiveIsize, $ strictly_positive_isize, isize, usize }
+
+impl_atomic! { AtomicPositiveI8, PositiveI8, AtomicI8, i8, "8", "positive" }
+impl_atomic! { AtomicNegativeI8, NegativeI8, AtomicI8, i8, "8", "negative" }
+impl_atomic_option! { AtomicOptionPositiveI8, PositiveI8, AtomicI8, i8, "8" }
+impl_atomic! { AtomicPositiveI16, PositiveI16, AtomicI16, i16, "16", "positive" }
+impl_atomic! { AtomicNegativeI16, NegativeI16, AtomicI16, i16, "16", "negative" }
+impl_atomic_option! { AtomicOptionPositiveI16, PositiveI16, AtomicI16, i16, "16" }
+impl_atomic! { AtomicPositiveI32, PositiveI32, AtomicI32, i32, "32", "positive" }
+impl_atomic! { AtomicNegativeI32, NegativeI32, AtomicI32, i32, "32", "negative" }
+impl_atomic_option! { AtomicOptionPositiveI32, PositiveI32, AtomicI32, i32, "32" }
+impl_atomic! { AtomicPositiveI64, PositiveI64, AtomicI64, i64, "64", "positive" }
+impl_atomic! { AtomicNegativeI64, NegativeI64, AtomicI64, i64, "64", "negative" }
+impl_atomic_option! { AtomicOptionPositiveI64, PositiveI64, AtomicI64, i64, "64" }
+impl_atomic! { AtomicPositiveIsize, PositiveIsize, AtomicIsize, isize, "ptr", "positive" }
+impl_atomic! { AtomicNegativeIsize, NegativeIsize, AtomicIsize, isize, "ptr", "negative" }
+impl_atomic_option! { AtomicOptionPositiveIsize, PositiveIsize, AtomicIsize, isize, "ptr" }
+
+impl_optional_index! { OptionalIndex, PositiveIsize, isize }
+impl_optional_index! { OptionalIndex32, PositiveI32, i32 }
+impl_optional_index! { OptionalIndex16, PositiveI16, i16 }
+
+impl_unsigned! { U7, PositiveI8, $ u7, i8, u8 }
+impl_primitive_from! { U7 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
+impl_positive_try_from! { u8, u16, u32, u64, u128, usize => U7, i8 }
+impl_positive_try_from! { i8, i16, i32, i64, i128, isize => U7, u8, i8 }
+
+impl_unsigned! { U15, PositiveI16, $ u15, i16, u16 }
+impl_from! { u8 => U15 }
+impl_primitive_from! { U15 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize }
+impl_primitive_try_from! { U15 => u8, i8 }
+impl_positive_try_from! { u16, u32, u64, u128, usize => U15, i16 }
+impl_positive_try_from! { i8, i16, i32, i64, i128, isize => U15, u16, i16 }
+
+impl_unsigned! { U31, PositiveI32, $ u31, i32, u32 }
+impl_from! { u8, u16 => U31 }
+impl_primitive_from! { U31 => u32, u64, u128, i32, i64, i128 }
+impl_primitive_try_from! { U31 => u8, u16, usize, i8, i16, isize }
+impl_positive_try_from! { u32, u64, u128, usize => U31, i32 }
+impl_positive_try_from! { i8, i16, i32, i64, i128, isize => U31, u32, i32 }
+
+impl_unsigned! { U63, PositiveI64, $ u63, i64, u64 }
+impl_from! { u8, u16, u32 => U63 }
+impl_primitive_from! { U63 => u64, u128, i64, i128 }
+impl_primitive_try_from! { U63 => u8, u16, u32, usize, i8, i16, i32, isize }
+impl_positive_try_from! { u64, u128, usize => U63, i64 }
+impl_positive_try_from! { i8, i16, i32, i64, i128, isize => U63, u64, i64 }
+
+impl_unsigned! { U127, PositiveI128, $ u127, i128, u128 }
+impl_from! { u8, u16, u32, u64 => U127 }
+impl_primitive_from! { U127 => u128, i128 }
+impl_primitive_try_from! { U127 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_positive_try_from! { u128, usize => U127, i128 }
+impl_positive_try_from! { i8, i16, i32, i64, i128, isize => U127, u128, i128 }
+
+impl_unsigned! { USizeHalf, PositiveIsize, $ usize_half, isize, usize }
+impl_from! { u8 => USizeHalf }
+impl_primitive_from! { USizeHalf => usize, isize }
+impl_primitive_try_from! { USizeHalf => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128 }
+impl_positive_try_from! { u16, u32, u64, u128, usize => USizeHalf, isize }
+impl_positive_try_from! { i8, i16, i32, i64, i128, isize => USizeHalf, usize, isize }
+
+impl_bounded_all! { BoundedI8, BoundedPositiveI8(PositiveI8), BoundedNegativeI8(NegativeI8), i8, u8 }
+impl_bounded_all! { BoundedI16, BoundedPositiveI16(PositiveI16), BoundedNegativeI16(NegativeI16), i16, u16 }
+impl_bounded_all! { BoundedI32, BoundedPositiveI32(PositiveI32), BoundedNegativeI32(NegativeI32), i32, u32 }
+impl_bounded_all! { BoundedI64, BoundedPositiveI64(PositiveI64), BoundedNegativeI64(NegativeI64), i64, u64 }
+
+impl_positive_float! { #[repr(align(4))] PositiveF32, NegativeF32, $ positive_f32, f32, u32 }
+impl_float_from! { PositiveI8, PositiveI16 => PositiveF32, f32 }
+impl_float_try_from_int! { PositiveI32(i32), PositiveI64(i64), PositiveI128(i128), PositiveIsize(isize) => PositiveF32, f32 }
+impl_int_try_from_float! { PositiveF32, f32 => PositiveI8(i8), PositiveI16(i16), PositiveI32(i32), PositiveI64(i64), PositiveI128(i128), PositiveIsize(isize) }
+impl_float_try_from_float! { f64, PositiveF64 => PositiveF32, f32 }
+impl_negative_float! { #[repr(align(4))] NegativeF32, PositiveF32, $ negative_f32, f32, u32 }
+impl_float_from! { NegativeI8, NegativeI16 => NegativeF32, f32 }
+impl_float_try_from_int! { NegativeI32(i32), NegativeI64(i64), NegativeI128(i128), NegativeIsize(isize) => NegativeF32, f32 }
+impl_int_try_from_float! { NegativeF32, f32 => NegativeI8(i8), NegativeI16(i16), NegativeI32(i32), NegativeI64(i64), NegativeI128(i128), NegativeIsize(isize) }
+impl_float_try_from_float! { f64, NegativeF64 => NegativeF32, f32 }
+
+impl_positive_float! { #[repr(align(8))] PositiveF64, NegativeF64, $ positive_f64, f64, u64 }
+impl_float_from! { PositiveI8, PositiveI16, PositiveI32, PositiveF32 => PositiveF64, f64 }
+impl_float_try_from_int! { PositiveI64(i64), PositiveI128(i128), PositiveIsize(isize) => PositiveF64, f64 }
+impl_int_try_from_float! { PositiveF64, f64 => PositiveI8(i8), PositiveI16(i16), PositiveI32(i32), PositiveI64(i64), PositiveI128(i128), PositiveIsize(isize) }
+impl_primitive_from! { PositiveF32 => f64 }
+impl_negative_float! { #[repr(align(8))] NegativeF64, PositiveF64, $ negative_f64, f64, u64 }
+impl_float_from! { NegativeI8, NegativeI16, NegativeI32, NegativeF32 => NegativeF64, f64 }
+impl_float_try_from_int! { NegativeI64(i64), NegativeI128(i128), NegativeIsize(isize) => NegativeF64, f64 }
+impl_int_try_from_float! { NegativeF64, f64 => NegativeI8(i8), NegativeI16(i16), NegativeI32(i32), NegativeI64(i64), NegativeI128(i128), NegativeIsize(isize) }
+impl_primitive_from! { NegativeF32 => f64 }
+
+impl TryFrom<f32> for PositiveF64 {
+    type Error = TryFromFloatError;
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_from(value as f64)
+    }
+}
+
+impl TryFrom<f32> for NegativeF64 {
+    type Error = TryFromFloatError;
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_from(value as f64)
+    }
+}
+
+/// The error type returned when a checked floating point conversion fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromFloatError(());
+
+impl core::fmt::Display for TryFromFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("out of range floating point type conversion attempted")
+    }
+}
+
+impl core::error::Error for TryFromFloatError {}
+
+#[derive(Copy, Clone)]
+#[repr(u8)]
+enum PositiveHighByte {
+    _0 = 0,
+    _1 = 1,
+    _2 = 2,
+    _3 = 3,
+    _4 = 4,
+    _5 = 5,
+    _6 = 6,
+    _7 = 7,
+    _8 = 8,
+    _9 = 9,
+    _10 = 10,
+    _11 = 11,
+    _12 = 12,
+    _13 = 13,
+    _14 = 14,
+    _15 = 15,
+    _16 = 16,
+    _17 = 17,
+    _18 = 18,
+    _19 = 19,
+    _20 = 20,
+    _21 = 21,
+    _22 = 22,
+    _23 = 23,
+    _24 = 24,
+    _25 = 25,
+    _26 = 26,
+    _27 = 27,
+    _28 = 28,
+    _29 = 29,
+    _30 = 30,
+    _31 = 31,
+    _32 = 32,
+    _33 = 33,
+    _34 = 34,
+    _35 = 35,
+    _36 = 36,
+    _37 = 37,
+    _38 = 38,
+    _39 = 39,
+    _40 = 40,
+    _41 = 41,
+    _42 = 42,
+    _43 = 43,
+    _44 = 44,
+    _45 = 45,
+    _46 = 46,
+    _47 = 47,
+    _48 = 48,
+    _49 = 49,
+    _50 = 50,
+    _51 = 51,
+    _52 = 52,
+    _53 = 53,
+    _54 = 54,
+    _55 = 55,
+    _56 = 56,
+    _57 = 57,
+    _58 = 58,
+    _59 = 59,
+    _60 = 60,
+    _61 = 61,
+    _62 = 62,
+    _63 = 63,
+    _64 = 64,
+    _65 = 65,
+    _66 = 66,
+    _67 = 67,
+    _68 = 68,
+    _69 = 69,
+    _70 = 70,
+    _71 = 71,
+    _72 = 72,
+    _73 = 73,
+    _74 = 74,
+    _75 = 75,
+    _76 = 76,
+    _77 = 77,
+    _78 = 78,
+    _79 = 79,
+    _80 = 80,
+    _81 = 81,
+    _82 = 82,
+    _83 = 83,
+    _84 = 84,
+    _85 = 85,
+    _86 = 86,
+    _87 = 87,
+    _88 = 88,
+    _89 = 89,
+    _90 = 90,
+    _91 = 91,
+    _92 = 92,
+    _93 = 93,
+    _94 = 94,
+    _95 = 95,
+    _96 = 96,
+    _97 = 97,
+    _98 = 98,
+    _99 = 99,
+    _100 = 100,
+    _101 = 101,
+    _102 = 102,
+    _103 = 103,
+    _104 = 104,
+    _105 = 105,
+    _106 = 106,
+    _107 = 107,
+    _108 = 108,
+    _109 = 109,
+    _110 = 110,
+    _111 = 111,
+    _112 = 112,
+    _113 = 113,
+    _114 = 114,
+    _115 = 115,
+    _116 = 116,
+    _117 = 117,
+    _118 = 118,
+    _119 = 119,
+    _120 = 120,
+    _121 = 121,
+    _122 = 122,
+    _123 = 123,
+    _124 = 124,
+    _125 = 125,
+    _126 = 126,
+    _127 = 127,
+}
+
+#[derive(Copy, Clone)]
+#[repr(u8)]
+enum NegativeHighByte {
+    _128 = 128,
+    _129 = 129,
+    _130 = 130,
+    _131 = 131,
+    _132 = 132,
+    _133 = 133,
+    _134 = 134,
+    _135 = 135,
+    _136 = 136,
+    _137 = 137,
+    _138 = 138,
+    _139 = 139,
+    _140 = 140,
+    _141 = 141,
+    _142 = 142,
+    _143 = 143,
+    _144 = 144,
+    _145 = 145,
+    _146 = 146,
+    _147 = 147,
+    _148 = 148,
+    _149 = 149,
+    _150 = 150,
+    _151 = 151,
+    _152 = 152,
+    _153 = 153,
+    _154 = 154,
+    _155 = 155,
+    _156 = 156,
+    _157 = 157,
+    _158 = 158,
+    _159 = 159,
+    _160 = 160,
+    _161 = 161,
+    _162 = 162,
+    _163 = 163,
+    _164 = 164,
+    _165 = 165,
+    _166 = 166,
+    _167 = 167,
+    _168 = 168,
+    _169 = 169,
+    _170 = 170,
+    _171 = 171,
+    _172 = 172,
+    _173 = 173,
+    _174 = 174,
+    _175 = 175,
+    _176 = 176,
+    _177 = 177,
+    _178 = 178,
+    _179 = 179,
+    _180 = 180,
+    _181 = 181,
+    _182 = 182,
+    _183 = 183,
+    _184 = 184,
+    _185 = 185,
+    _186 = 186,
+    _187 = 187,
+    _188 = 188,
+    _189 = 189,
+    _190 = 190,
+    _191 = 191,
+    _192 = 192,
+    _193 = 193,
+    _194 = 194,
+    _195 = 195,
+    _196 = 196,
+    _197 = 197,
+    _198 = 198,
+    _199 = 199,
+    _200 = 200,
+    _201 = 201,
+    _202 = 202,
+    _203 = 203,
+    _204 = 204,
+    _205 = 205,
+    _206 = 206,
+    _207 = 207,
+    _208 = 208,
+    _209 = 209,
+    _210 = 210,
+    _211 = 211,
+    _212 = 212,
+    _213 = 213,
+    _214 = 214,
+    _215 = 215,
+    _216 = 216,
+    _217 = 217,
+    _218 = 218,
+    _219 = 219,
+    _220 = 220,
+    _221 = 221,
+    _222 = 222,
+    _223 = 223,
+    _224 = 224,
+    _225 = 225,
+    _226 = 226,
+    _227 = 227,
+    _228 = 228,
+    _229 = 229,
+    _230 = 230,
+    _231 = 231,
+    _232 = 232,
+    _233 = 233,
+    _234 = 234,
+    _235 = 235,
+    _236 = 236,
+    _237 = 237,
+    _238 = 238,
+    _239 = 239,
+    _240 = 240,
+    _241 = 241,
+    _242 = 242,
+    _243 = 243,
+    _244 = 244,
+    _245 = 245,
+    _246 = 246,
+    _247 = 247,
+    _248 = 248,
+    _249 = 249,
+    _250 = 250,
+    _251 = 251,
+    _252 = 252,
+    _253 = 253,
+    _254 = 254,
+    _255 = 255,
+}
+
+#[derive(Copy, Clone)]
+#[repr(u8)]
+enum NonPositiveHighByte {
+    _0 = 0,
+    _128 = 128,
+    _129 = 129,
+    _130 = 130,
+    _131 = 131,
+    _132 = 132,
+    _133 = 133,
+    _134 = 134,
+    _135 = 135,
+    _136 = 136,
+    _137 = 137,
+    _138 = 138,
+    _139 = 139,
+    _140 = 140,
+    _141 = 141,
+    _142 = 142,
+    _143 = 143,
+    _144 = 144,
+    _145 = 145,
+    _146 = 146,
+    _147 = 147,
+    _148 = 148,
+    _149 = 149,
+    _150 = 150,
+    _151 = 151,
+    _152 = 152,
+    _153 = 153,
+    _154 = 154,
+    _155 = 155,
+    _156 = 156,
+    _157 = 157,
+    _158 = 158,
+    _159 = 159,
+    _160 = 160,
+    _161 = 161,
+    _162 = 162,
+    _163 = 163,
+    _164 = 164,
+    _165 = 165,
+    _166 = 166,
+    _167 = 167,
+    _168 = 168,
+    _169 = 169,
+    _170 = 170,
+    _171 = 171,
+    _172 = 172,
+    _173 = 173,
+    _174 = 174,
+    _175 = 175,
+    _176 = 176,
+    _177 = 177,
+    _178 = 178,
+    _179 = 179,
+    _180 = 180,
+    _181 = 181,
+    _182 = 182,
+    _183 = 183,
+    _184 = 184,
+    _185 = 185,
+    _186 = 186,
+    _187 = 187,
+    _188 = 188,
+    _189 = 189,
+    _190 = 190,
+    _191 = 191,
+    _192 = 192,
+    _193 = 193,
+    _194 = 194,
+    _195 = 195,
+    _196 = 196,
+    _197 = 197,
+    _198 = 198,
+    _199 = 199,
+    _200 = 200,
+    _201 = 201,
+    _202 = 202,
+    _203 = 203,
+    _204 = 204,
+    _205 = 205,
+    _206 = 206,
+    _207 = 207,
+    _208 = 208,
+    _209 = 209,
+    _210 = 210,
+    _211 = 211,
+    _212 = 212,
+    _213 = 213,
+    _214 = 214,
+    _215 = 215,
+    _216 = 216,
+    _217 = 217,
+    _218 = 218,
+    _219 = 219,
+    _220 = 220,
+    _221 = 221,
+    _222 = 222,
+    _223 = 223,
+    _224 = 224,
+    _225 = 225,
+    _226 = 226,
+    _227 = 227,
+    _228 = 228,
+    _229 = 229,
+    _230 = 230,
+    _231 = 231,
+    _232 = 232,
+    _233 = 233,
+    _234 = 234,
+    _235 = 235,
+    _236 = 236,
+    _237 = 237,
+    _238 = 238,
+    _239 = 239,
+    _240 = 240,
+    _241 = 241,
+    _242 = 242,
+    _243 = 243,
+    _244 = 244,
+    _245 = 245,
+    _246 = 246,
+    _247 = 247,
+    _248 = 248,
+    _249 = 249,
+    _250 = 250,
+    _251 = 251,
+    _252 = 252,
+    _253 = 253,
+    _254 = 254,
+    _255 = 255,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Not, Rem, RemAssign,
+    };
+    use proptest::prelude::*;
+
+    macro_rules! test_unary {
+        ($ty:ident, $base:ident ($range:expr) :: $($method:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(n in $range) {
+                    assert_eq!($ty::new(n).map(|n| n.$method()), Some((n as $base).$method()));
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_unary_op {
+        ($ty:ident, $base:ident ($range:expr) :: $($method:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(n in $range) {
+                    assert_eq!($ty::new(n).map(|n| n.$method().get()), Some((n as $base).$method()));
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_unary_checked {
+        ($ty:ident, $base:ident ($range:expr) :: $($method:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(n in $range) {
+                    assert_eq!($ty::new(n).and_then(|n| n.$method()), (n as $base).$method());
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_binary {
+        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(a in $range1, b in $range2) {
+                    assert_eq!(
+                        $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.$method(b)).map(|a| a.get()),
+                        Some((a as $base).$method(b as $base)),
+                    );
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_binary_checked {
+        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(a in $range1, b in $range2) {
+                    assert_eq!(
+                        $ty::new(a).zip($ty::new(b)).and_then(|(a, b)| a.$method(b)).map(|a| a.get()),
+                        (a as $base).$method(b as $base),
+                    );
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_assign {
+        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(a in $range1, b in $range2) {
+                    let mut a1 = $ty::new(a);
+                    let mut a2 = a;
+                    if let Some((a, b)) = a1.as_mut().zip($ty::new(b)) {
+                        a.$method(b);
+                    }
+                    a2.$method(b);
+                    assert_eq!(a1.map(|a| a.get()), Some(a2));
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_wrapping {
+        ($ty:ident, $base:ident, $uns:ident ($range1:expr, $range2:expr) $op:tt ($mask:expr)) => {
+            proptest! {
+                #[test]
+                fn wrapping_add(a in $range1, b in $range2) {
+                    assert_eq!(
+                        $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.wrapping_add(b).get()),
+                        Some(((a as $uns).wrapping_add(b as $uns) $op $mask) as $base),
+                    );
+                }
+                #[test]
+                fn wrapping_sub(a in $range1, b in $range2) {
+                    assert_eq!(
+                        $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.wrapping_sub(b).get()),
+                        Some(((a as $uns).wrapping_sub(b as $uns) $op $mask) as $base),
+                    );
+                }
+                #[test]
+                fn wrapping_mul(a in $range1, b in $range2) {
+                    assert_eq!(
+                        $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.wrapping_mul(b).get()),
+                        Some(((a as $uns).wrapping_mul(b as $uns) $op $mask) as $base),
+                    );
+                }
+                #[test]
+                fn wrapping_neg(n in $range1) {
+                    assert_eq!(
+                        $ty::new(n).map(|n| n.wrapping_neg().get()),
+                        Some(((n as $uns).wrapping_neg() $op $mask) as $base),
+                    );
+                }
+                #[test]
+                fn wrapping_pow(n in $range1, exp in 0..u32::MAX) {
+                    assert_eq!(
+                        $ty::new(n).map(|n| n.wrapping_pow(exp).get()),
+                        Some(((n as $uns).wrapping_pow(exp) $op $mask) as $base),
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_overflowing {
+        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident, $wrapping:ident, $checked:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(a in $range1, b in $range2) {
+                    let (x, y) = ($ty::new(a).unwrap(), $ty::new(b).unwrap());
+                    assert_eq!(
+                        x.$method(y),
+                        (x.$wrapping(y), a.$checked(b).and_then($ty::new).is_none()),
+                    );
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_strict {
+        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident, $checked:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(a in $range1, b in $range2) {
+                    let (a, b) = ($ty::new(a).unwrap(), $ty::new(b).unwrap());
+                    if let Some(n) = a.$checked(b) {
+                        assert_eq!(a.$method(b), n);
+                    }
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_unchecked {
+        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident, $checked:ident),+ $(,)?) => {
+            proptest! {
+                $(#[test] fn $method(a in $range1, b in $range2) {
+                    let (a, b) = ($ty::new(a).unwrap(), $ty::new(b).unwrap());
+                    if let Some(n) = a.$checked(b) {
+                        assert_eq!(unsafe { a.$method(b) }, n);
+                    }
+                })+
+            }
+        };
+    }
+
+    macro_rules! test_shift {
+        ($ty:ident, $base:ident, $uns:ident ($range:expr) $op:tt ($mask:expr), $sat:expr) => {
+            proptest! {
+                #[test]
+                fn shr(n in $range, rhs in 0..$base::BITS) {
+                    let x = $ty::new(n).unwrap();
+                    assert_eq!((x >> rhs).get(), n >> rhs);
+                    let mut y = x;
+                    y >>= rhs;
+                    assert_eq!(y.get(), n >> rhs);
+                }
+                #[test]
+                fn checked_shl(n in $range, rhs in 0..$base::BITS * 2) {
+                    let expected = n.checked_shl(rhs).filter(|m| m >> rhs == n).and_then($ty::new);
+                    let x = $ty::new(n).unwrap();
+                    assert_eq!(x.checked_shl(rhs), expected);
+                    assert_eq!(x.saturating_shl(rhs), expected.unwrap_or(if n == 0 { x } else { $sat }));
+                }
+                #[test]
+                fn rotate_magnitude(n in $range, rhs in 0..$base::BITS * 2) {
+                    let x = $ty::new(n).unwrap();
+                    let mut v = n as $uns & $base::MAX as $uns;
+                    for _ in 0..rhs {
+                        v = ((v << 1) & $base::MAX as $uns) | (v >> ($base::BITS - 2));
+                    }
+                    assert_eq!(x.rotate_left_magnitude(rhs).get(), (v $op $mask) as $base);
+                    assert_eq!(x.rotate_left_magnitude(rhs).rotate_right_magnitude(rhs), x);
+                }
+            }
+        };
+    }
+
+    macro_rules! test_bytes {
+        ($ty:ident, $base:ident ($range:expr)) => {
+            proptest! {
+                #[test]
+                fn to_bytes(n in $range) {
+                    let x = $ty::new(n).unwrap();
+                    assert_eq!(x.to_be_bytes(), n.to_be_bytes());
+                    assert_eq!(x.to_le_bytes(), n.to_le_bytes());
+                    assert_eq!(x.to_ne_bytes(), n.to_ne_bytes());
+                }
+                #[test]
+                fn from_bytes(n in $base::MIN..=$base::MAX) {
+                    assert_eq!($ty::from_be_bytes(n.to_be_bytes()), $ty::new(n));
+                    assert_eq!($ty::from_le_bytes(n.to_le_bytes()), $ty::new(n));
+                    assert_eq!($ty::from_ne_bytes(n.to_ne_bytes()), $ty::new(n));
+                }
+            }
+        };
+    }
+
+    macro_rules! test_clamp {
+        ($ty:ident, $base:ident, $min:expr, $max:expr) => {
+            proptest! {
+                #[test]
+                fn clamp_from_i128(n: i128) {
+                    let expected = n.clamp($min as i128, $max as i128) as $base;
+                    assert_eq!($ty::clamp_from_i128(n).get(), expected);
+                    assert_eq!($ty::saturating_from(n).get(), expected);
+                }
+                #[test]
+                fn clamp_from_u128(n: u128) {
+                    let expected = n.min($max.max(0) as u128) as i128;
+                    let expected = expected.clamp($min as i128, $max as i128) as $base;
+                    assert_eq!($ty::clamp_from_u128(n).get(), expected);
+                    assert_eq!($ty::saturating_from(n).get(), expected);
+                }
+                #[test]
+                fn clamp_from_i64(n: i64) {
+                    let expected = (n as i128).clamp($min as i128, $max as i128) as $base;
+                    assert_eq!($ty::clamp_from_i64(n).get(), expected);
+                    assert_eq!($ty::saturating_from(n).get(), expected);
+                }
+                #[test]
+                fn clamp_from_u64(n: u64) {
+                    let expected = (n as i128).clamp($min as i128, $max as i128) as $base;
+                    assert_eq!($ty::clamp_from_u64(n).get(), expected);
+                    assert_eq!($ty::saturating_from(n).get(), expected);
+                }
+                #[test]
+                fn clamp_from_narrow(a: i32, b: u32, c: i16, d: u16, e: i8, f: u8, g: isize, h: usize) {
+                    let clamp = |n: i128| n.clamp($min as i128, $max as i128) as $base;
+                    assert_eq!($ty::clamp_from_i32(a).get(), clamp(a as i128));
+                    assert_eq!($ty::clamp_from_u32(b).get(), clamp(b as i128));
+                    assert_eq!($ty::clamp_from_i16(c).get(), clamp(c as i128));
+                    assert_eq!($ty::clamp_from_u16(d).get(), clamp(d as i128));
+                    assert_eq!($ty::clamp_from_i8(e).get(), clamp(e as i128));
+                    assert_eq!($ty::clamp_from_u8(f).get(), clamp(f as i128));
+                    assert_eq!($ty::clamp_from_isize(g).get(), clamp(g as i128));
+                    assert_eq!($ty::clamp_from_usize(h).get(), clamp(h as i128));
+                }
+                #[test]
+                fn saturating_from(a: i8, b: u16, c: isize, d: usize) {
+                    let clamp = |n: i128| n.clamp($min as i128, $max as i128) as $base;
+                    assert_eq!($ty::saturating_from(a).get(), clamp(a as i128));
+                    assert_eq!($ty::saturating_from(b).get(), clamp(b as i128));
+                    assert_eq!($ty::saturating_from(c).get(), clamp(c as i128));
+                    assert_eq!($ty::saturating_from(d).get(), clamp(d as i128));
+                }
+            }
+        };
+    }
+
+    macro_rules! test_type {
+        ($base:ident, $uns:ident, $pos:ident, $neg:ident) => {
+            mod $base {
+                use super::*;
+                mod positive {
+                    use super::*;
+                    proptest! {
+                        #[test]
+                        fn valid(n in 0..=$base::MAX) {
+                            assert_eq!($pos::new(n).map(|n| n.get()), Some(n));
+                        }
+                        #[test]
+                        fn invalid(n in $base::MIN..-1) {
+                            assert_eq!($pos::new(n).map(|n| n.get()), None);
+                        }
+                        #[test]
+                        fn checked_neg(n in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(n).and_then(|n| n.checked_neg()),
+                                n.checked_neg().and_then($neg::new),
+                            );
+                        }
+                        #[test]
+                        fn checked_sub(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).and_then(|(a, b)| a.checked_sub(b)),
+                                a.checked_sub(b).and_then($pos::new),
+                            );
+                        }
+                        #[test]
+                        fn checked_add_signed(a in 0..=$base::MAX, b in $base::MIN..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).and_then(|a| a.checked_add_signed(b)),
+                                a.checked_add(b).and_then($pos::new),
+                            );
+                        }
+                        #[test]
+                        fn checked_ilog(n in 0..=$base::MAX, base in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(n).zip($pos::new(base)).and_then(|(n, base)| n.checked_ilog(base)),
+                                n.checked_ilog(base),
+                            );
+                        }
+                        #[test]
+                        fn isqrt(n in 0..=$base::MAX) {
+                            let r = $pos::new(n).unwrap().isqrt().get() as $uns;
+                            let n = n as $uns;
+                            assert!(r * r <= n);
+                            assert!((r + 1).checked_mul(r + 1).map_or(true, |m| m > n));
+                        }
+                        #[test]
+                        fn div_ceil(a in 0..=$base::MAX, b in 1..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.div_ceil(b).get()),
+                                Some((a as $uns).div_ceil(b as $uns) as $base),
+                            );
+                        }
+                        #[test]
+                        fn checked_next_multiple_of(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).and_then(|(a, b)| a.checked_next_multiple_of(b)),
+                                (a as $uns).checked_next_multiple_of(b as $uns).and_then(|n| $pos::try_from(n).ok()),
+                            );
+                        }
+                        #[test]
+                        fn is_multiple_of(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.is_multiple_of(b)),
+                                Some(a.checked_rem(b).map_or(a == 0, |r| r == 0)),
+                            );
+                        }
+                        #[test]
+                        fn midpoint(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.midpoint(b).get()),
+                                Some(a / 2 + b / 2 + (a % 2 + b % 2) / 2),
+                            );
+                        }
+                        #[test]
+                        fn abs_diff(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.abs_diff(b).get()),
+                                Some((a - b).abs()),
+                            );
+                        }
+                        #[test]
+                        fn gcd_lcm(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            let (x, y) = ($pos::new(a).unwrap(), $pos::new(b).unwrap());
+                            let gcd = x.gcd(y).get();
+                            assert_eq!(gcd, y.gcd(x).get());
+                            if gcd == 0 {
+                                assert_eq!((a, b), (0, 0));
+                                assert_eq!(x.checked_lcm(y), Some($pos::MIN));
+                            } else {
+                                assert_eq!((a % gcd, b % gcd), (0, 0));
+                                let (mut p, mut q) = (a / gcd, b / gcd);
+                                while q != 0 {
+                                    (p, q) = (q, p % q);
+                                }
+                                assert_eq!(p, 1);
+                                assert_eq!(x.checked_lcm(y).map(|n| n.get()), (a / gcd).checked_mul(b));
+                            }
+                        }
+                        #[test]
+                        fn signed_diff(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.signed_diff(b)),
+                                a.checked_sub(b),
+                            );
+                        }
+                        #[test]
+                        fn checked_div_unsigned(a in 0..=$base::MAX, b in 0..=$uns::MAX) {
+                            assert_eq!(
+                                $pos::new(a).and_then(|a| a.checked_div_unsigned(b)),
+                                (a as $uns).checked_div(b).and_then(|n| $pos::try_from(n).ok()),
+                            );
+                        }
+                        #[test]
+                        fn checked_rem_unsigned(a in 0..=$base::MAX, b in 0..=$uns::MAX) {
+                            assert_eq!(
+                                $pos::new(a).and_then(|a| a.checked_rem_unsigned(b)),
+                                (a as $uns).checked_rem(b).and_then(|n| $pos::try_from(n).ok()),
+                            );
+                        }
+                        #[test]
+                        fn checked_pow(a in 0..=$base::MAX, b in 0..u32::MAX) {
+                            assert_eq!(
+                                $pos::new(a).and_then(|a| a.checked_pow(b)).map(|n| n.get()),
+                                a.checked_pow(b),
+                            );
+                        }
+                        #[test]
+                        fn checked_next_power_of_two(n in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(n).and_then(|n| n.checked_next_power_of_two()),
+                                (n as $uns).checked_next_power_of_two().and_then(|n| $pos::try_from(n).ok()),
+                            );
+                        }
+                        #[test]
+                        fn saturating_sub(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.saturating_sub(b)).map(|a| a.get()),
+                                Some(a.saturating_sub(b).max(0)),
+                            );
+                        }
+                        #[test]
+                        fn saturating_pow(a in 0..=$base::MAX, b in 0..u32::MAX) {
+                            assert_eq!(
+                                $pos::new(a).map(|a| a.saturating_pow(b)).map(|a| a.get()),
+                                Some((a as $base).saturating_pow(b)),
+                            );
+                        }
+                    }
+                    test_unary_op! { $pos, $base (0..=$base::MAX) :: not }
+                    test_binary! { $pos, $base (0..=$base::MAX, 1..=$base::MAX) :: div, rem }
+                    test_unary! { $pos, $base (0..=$base::MAX)
+                    :: count_zeros, count_ones, leading_zeros, trailing_zeros, signum }
+                    test_unary! { $pos, $uns (0..=$base::MAX) :: is_power_of_two }
+                    test_unary! { $pos, $base (1..=$base::MAX) :: ilog2, ilog10 }
+                    test_unary_checked! { $pos, $base (1..=$base::MAX) :: checked_ilog2, checked_ilog10 }
+                    test_binary_checked! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
+                    :: checked_add, checked_mul, checked_div, checked_rem }
+                    test_binary! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
+                    :: saturating_add, saturating_mul, bitor, bitand, bitxor }
+                    test_assign! { $pos, $base (0..=$base::MAX, 1..=$base::MAX) :: div_assign, rem_assign }
+                    test_assign! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
+                    :: bitor_assign, bitand_assign, bitxor_assign }
+                    test_wrapping! { $pos, $base, $uns (0..=$base::MAX, 0..=$base::MAX) & ($base::MAX as $uns) }
+                    test_overflowing! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
+                    :: overflowing_add, wrapping_add, checked_add, overflowing_sub, wrapping_sub, checked_sub,
+                    overflowing_mul, wrapping_mul, checked_mul }
+                    test_bytes! { $pos, $base (0..=$base::MAX) }
+                    test_clamp! { $pos, $base, 0, $base::MAX }
+                    test_shift! { $pos, $base, $uns (0..=$base::MAX) | (0), $pos::MAX }
+                    test_strict! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
+                    :: strict_add, checked_add, strict_sub, checked_sub, strict_mul, checked_mul, strict_div, checked_div }
+                    test_unchecked! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
+                    :: unchecked_add, checked_add, unchecked_sub, checked_sub, unchecked_mul, checked_mul }
+                    proptest! {
+                        #[test]
+                        fn overflowing_pow(n in 0..=$base::MAX, exp in 0..u32::MAX) {
+                            let x = $pos::new(n).unwrap();
+                            assert_eq!(
+                                x.overflowing_pow(exp),
+                                (x.wrapping_pow(exp), n.checked_pow(exp).is_none()),
+                            );
+                        }
+                        #[test]
+                        fn strict_pow(n in 0..=$base::MAX, exp in 0..u32::MAX) {
+                            let x = $pos::new(n).unwrap();
+                            if let Some(m) = x.checked_pow(exp) {
+                                assert_eq!(x.strict_pow(exp), m);
+                            }
+                        }
+                    }
+                    #[test]
+                    #[should_panic = "attempt to add with overflow in Positive"]
+                    fn strict_add_overflow() {
+                        $pos::MAX.strict_add($pos::new(1).unwrap());
+                    }
+                    #[test]
+                    #[should_panic = "attempt to subtract with overflow in Positive"]
+                    fn strict_sub_overflow() {
+                        $pos::MIN.strict_sub($pos::new(1).unwrap());
+                    }
+                    #[test]
+                    #[should_panic = "attempt to divide by zero in Positive"]
+                    fn strict_div_zero() {
+                        $pos::MAX.strict_div($pos::MIN);
+                    }
+                    test_binary! { $pos, $base (0..=$base::MAX / 2, 0..=$base::MAX / 2) :: add }
+                    test_binary! { $pos, $base (0..=$base::MAX >> ($base::BITS / 2), 0..=$base::MAX >> ($base::BITS / 2)) :: mul }
+                    test_assign! { $pos, $base (0..=$base::MAX / 2, 0..=$base::MAX / 2) :: add_assign }
+                    test_assign! { $pos, $base (0..=$base::MAX >> ($base::BITS / 2), 0..=$base::MAX >> ($base::BITS / 2)) :: mul_assign }
+                    proptest! {
+                        #[test]
+                        fn sub(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            let (a, b) = (a.max(b), a.min(b));
+                            assert_eq!($pos::new(a).zip($pos::new(b)).map(|(a, b)| (a - b).get()), Some(a - b));
+                        }
+                        #[test]
+                        fn sub_assign(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                            let (a, b) = (a.max(b), a.min(b));
+                            let mut n = $pos::new(a).unwrap();
+                            n -= $pos::new(b).unwrap();
+                            assert_eq!(n.get(), a - b);
+                        }
+                    }
+                    #[test]
+                    #[cfg_attr(debug_assertions, should_panic = "attempt to add with overflow")]
+                    fn add_overflow() {
+                        assert_eq!(($pos::MAX + $pos::new(1).unwrap()).get(), 0);
+                    }
+                    #[test]
+                    #[cfg_attr(debug_assertions, should_panic = "attempt to subtract with overflow")]
+                    fn sub_overflow() {
+                        assert_eq!(($pos::new(0).unwrap() - $pos::new(1).unwrap()).get(), $base::MAX);
+                    }
+                    #[test]
+                    #[cfg_attr(debug_assertions, should_panic = "attempt to multiply with overflow")]
+                    fn mul_overflow() {
+                        assert_eq!(($pos::MAX * $pos::new(2).unwrap()).get(), $base::MAX - 1);
+                    }
+                }
+                #[test]
+                fn layout() {
+                    assert_eq!(size_of::<$pos>(), size_of::<$base>());
+                    assert_eq!(align_of::<$pos>(), align_of::<$base>());
+                    assert_eq!(size_of::<Option<$pos>>(), size_of::<$base>());
+                    assert_eq!(size_of::<$neg>(), size_of::<$base>());
+                    assert_eq!(align_of::<$neg>(), align_of::<$base>());
+                    assert_eq!(size_of::<Option<$neg>>(), size_of::<$base>());
+                }
+                mod negative {
+                    use super::*;
+                    proptest! {
+                        #[test]
+                        fn valid(n in $base::MIN..0) {
+                            assert_eq!($neg::new(n).map(|n| n.get()), Some(n));
+                        }
+                        #[test]
+                        fn invalid(n in 0..=$base::MAX) {
+                            assert_eq!($neg::new(n).map(|n| n.get()), None);
+                        }
+                        #[test]
+                        fn checked_abs(n in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(n).and_then(|n| n.checked_abs()).map(|n| n.get()),
+                                n.checked_abs(),
+                            );
+                        }
+                        #[test]
+                        fn checked_neg(n in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(n).and_then(|n| n.checked_neg()).map(|n| n.get()),
+                                n.checked_neg(),
+                            );
+                        }
+                        #[test]
+                        fn checked_pow(n in $base::MIN..0, exp in 0..u32::MAX) {
+                            assert_eq!(
+                                $neg::new(n).and_then(|n| n.checked_pow(exp)),
+                                n.checked_pow(exp).map(|m| match m {
+                                    0.. => Either::Positive($pos::new(m).unwrap()),
+                                    _ => Either::Negative($neg::new(m).unwrap()),
+                                }),
+                            );
+                        }
+                        #[test]
+                        fn overflowing_mul(a in $base::MIN..0, b in $base::MIN..0) {
+                            let (x, y) = ($neg::new(a).unwrap(), $neg::new(b).unwrap());
+                            assert_eq!(x.overflowing_mul(y), (x.wrapping_mul(y), true));
+                        }
+                        #[test]
+                        fn overflowing_pow(n in $base::MIN..0, exp in 0..u32::MAX) {
+                            let x = $neg::new(n).unwrap();
+                            assert_eq!(
+                                x.overflowing_pow(exp),
+                                (x.wrapping_pow(exp), n.checked_pow(exp).and_then($neg::new).is_none()),
+                            );
+                        }
+                        #[test]
+                        fn unsigned_abs(n in $base::MIN..0) {
+                            assert_eq!($neg::new(n).map(|n| n.unsigned_abs()), Some(n.unsigned_abs()));
+                        }
+                        #[test]
+                        fn ilog2_magnitude(n in $base::MIN..0) {
+                            assert_eq!($neg::new(n).map(|n| n.ilog2_magnitude()), Some(n.unsigned_abs().ilog2()));
+                        }
+                        #[test]
+                        fn ilog10_magnitude(n in $base::MIN..0) {
+                            assert_eq!($neg::new(n).map(|n| n.ilog10_magnitude()), Some(n.unsigned_abs().ilog10()));
+                        }
+                        #[test]
+                        fn is_negated_power_of_two(n in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(n).map(|n| n.is_negated_power_of_two()),
+                                Some(n.unsigned_abs().is_power_of_two()),
+                            );
+                        }
+                        #[test]
+                        fn checked_sub(a in $base::MIN..0, b in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(a).zip($neg::new(b)).and_then(|(a, b)| a.checked_sub(b)),
+                                a.checked_sub(b).and_then($neg::new),
+                            );
+                        }
+                        #[test]
+                        fn checked_mul_positive(a in $base::MIN..0, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $neg::new(a)
+                                    .zip($pos::new(b))
+                                    .and_then(|(a, b)| a.checked_mul_positive(b)),
+                                a.checked_mul(b).and_then($neg::new),
+                            );
+                        }
+                        #[test]
+                        fn checked_rem_euclid(a in $base::MIN..0, b in $base::MIN..=$base::MAX) {
+                            assert_eq!(
+                                $neg::new(a).and_then(|a| a.checked_rem_euclid(b)).map(|n| n.get()),
+                                a.checked_rem_euclid(b),
+                            );
+                        }
+                        #[test]
+                        fn saturating_abs(n in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(n).map(|n| n.saturating_abs().get()),
+                                Some(n.saturating_abs()),
+                            );
+                        }
+                        #[test]
+                        fn saturating_neg(n in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(n).map(|n| n.saturating_neg().get()),
+                                Some(n.saturating_neg()),
+                            );
+                        }
+                        #[test]
+                        fn saturating_sub(a in $base::MIN..0, b in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(a)
+                                    .zip($neg::new(b))
+                                    .map(|(a, b)| a.saturating_sub(b).get()),
+                                Some(a.saturating_sub(b).min(-1)),
+                            );
+                        }
+                        #[test]
+                        fn saturating_mul_positive(a in $base::MIN..0, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $neg::new(a)
+                                    .zip($pos::new(b))
+                                    .map(|(a, b)| a.saturating_mul_positive(b).get()),
+                                Some(a.saturating_mul(b).min(-1)),
+                            );
+                        }
+                        #[test]
+                        fn bitxor_assign(a in $base::MIN..0, b in 0..=$base::MAX) {
+                            let mut a1 = $neg::new(a);
+                            let mut a2 = a;
+                            if let Some((a, b)) = a1.as_mut().zip($pos::new(b)) {
+                                a.bitxor_assign(b);
+                            }
+                            a2.bitxor_assign(b);
+                            assert_eq!(a1.map(|a| a.get()), Some(a2));
+                        }
+                    }
+                    test_unary_op! { $neg, $base ($base::MIN..0) :: not }
+                    test_unary! { $neg, $base ($base::MIN..0)
+                    :: count_zeros, count_ones, leading_zeros, trailing_zeros, signum }
+                    test_binary_checked! { $neg, $base ($base::MIN..0, $base::MIN..0)
+                    :: checked_add, checked_mul, checked_div, checked_div_euclid }
+                    test_binary! { $neg, $base ($base::MIN..0, $base::MIN..0)
+                    :: saturating_add, saturating_mul, bitor, bitand, bitxor }
+                    test_assign! { $neg, $base ($base::MIN..0, $base::MIN..0)
+                    :: bitor_assign, bitand_assign }
+                    test_wrapping! { $neg, $base, $uns ($base::MIN..0, $base::MIN..0) | ($base::MIN as $uns) }
+                    test_overflowing! { $neg, $base ($base::MIN..0, $base::MIN..0)
+                    :: overflowing_add, wrapping_add, checked_add, overflowing_sub, wrapping_sub, checked_sub }
+                    test_bytes! { $neg, $base ($base::MIN..0) }
+                    test_clamp! { $neg, $base, $base::MIN, -1 }
+                    test_shift! { $neg, $base, $uns ($base::MIN..0) | ($base::MIN as $uns), $neg::MIN }
+                    test_strict! { $neg, $base ($base::MIN..0, $base::MIN..0)
+                    :: strict_add, checked_add, strict_sub, checked_sub, strict_mul, checked_mul, strict_div, checked_div }
+                    test_unchecked! { $neg, $base ($base::MIN..0, $base::MIN..0)
+                    :: unchecked_add, checked_add, unchecked_sub, checked_sub, unchecked_mul, checked_mul }
+                    proptest! {
+                        #[test]
+                        fn strict_pow(n in $base::MIN..0, exp in 0..u32::MAX) {
+                            let x = $neg::new(n).unwrap();
+                            if let Some(m) = n.checked_pow(exp) {
+                                match x.strict_pow(exp) {
+                                    Either::Positive(p) => assert_eq!((p.get(), exp % 2), (m, 0)),
+                                    Either::Negative(p) => assert_eq!((p.get(), exp % 2), (m, 1)),
+                                }
+                            }
+                        }
+                    }
+                    #[test]
+                    fn overflowing_in_domain() {
+                        let (a, b) = ($neg::new(-2).unwrap(), $neg::new(-3).unwrap());
+                        assert_eq!(a.overflowing_mul(b), ($neg::new($base::MIN + 6).unwrap(), true));
+                        assert_eq!(a.overflowing_pow(3), ($neg::new(-8).unwrap(), false));
+                        assert_eq!(a.overflowing_pow(0), ($neg::new($base::MIN + 1).unwrap(), true));
+                    }
+                    #[test]
+                    #[should_panic = "attempt to add with overflow in Negative"]
+                    fn strict_add_overflow() {
+                        $neg::MIN.strict_add($neg::MAX);
+                    }
+                    #[test]
+                    #[should_panic = "attempt to divide with overflow in Negative"]
+                    fn strict_div_overflow() {
+                        $neg::MIN.strict_div($neg::MAX);
+                    }
+                    #[test]
+                    #[should_panic = "attempt to multiply with overflow in Negative"]
+                    fn strict_pow_overflow() {
+                        $neg::MIN.strict_pow(2);
+                    }
+                    proptest! {
+                        #[test]
+                        fn neg(n in $base::MIN + 1..0) {
+                            assert_eq!($neg::new(n).map(|n| (-n).get()), Some(-n));
+                        }
+                        #[test]
+                        fn neg_positive(n in 1..=$base::MAX) {
+                            assert_eq!($pos::new(n).map(|n| (-n).get()), Some(-n));
+                        }
+                        #[test]
+                        fn mul(
+                            a in -($base::MAX >> ($base::BITS / 2))..0,
+                            b in -($base::MAX >> ($base::BITS / 2))..0,
+                        ) {
+                            assert_eq!($neg::new(a).zip($neg::new(b)).map(|(a, b)| (a * b).get()), Some(a * b));
+                        }
+                        #[test]
+                        fn mul_positive(
+                            a in -($base::MAX >> ($base::BITS / 2))..0,
+                            b in 1..=$base::MAX >> ($base::BITS / 2),
+                        ) {
+                            let (na, pb) = ($neg::new(a).unwrap(), $pos::new(b).unwrap());
+                            assert_eq!((na * pb).get(), a * b);
+                            assert_eq!((pb * na).get(), a * b);
+                            let mut n = na;
+                            n *= pb;
+                            assert_eq!(n.get(), a * b);
+                        }
+                    }
+                    proptest! {
+                        #[test]
+                        fn checked_add_signed(a in $base::MIN..0, b in $base::MIN..=$base::MAX) {
+                            assert_eq!(
+                                $neg::new(a).and_then(|a| a.checked_add_signed(b)),
+                                a.checked_add(b).and_then($neg::new),
+                            );
+                        }
+                        #[test]
+                        fn signed_diff(a in $base::MIN..0, b in $base::MIN..0) {
+                            assert_eq!(
+                                $neg::new(a).zip($neg::new(b)).map(|(a, b)| a.signed_diff(b)),
+                                a.checked_sub(b),
+                            );
+                        }
+                        #[test]
+                        fn add_positive(a in $base::MIN..0, b in 0..=$base::MAX) {
+                            let (na, pb) = ($neg::new(a).unwrap(), $pos::new(b).unwrap());
+                            assert_eq!(Some(na + pb), a.checked_add(b));
+                            assert_eq!(Some(pb + na), b.checked_add(a));
+                        }
+                    }
+                    proptest! {
+                        #[test]
+                        fn checked_div_positive(a in $base::MIN..0, b in 0..=$base::MAX) {
+                            assert_eq!(
+                                $neg::new(a).zip($pos::new(b)).and_then(|(a, b)| a.checked_div_positive(b)),
+                                a.checked_div(b),
+                            );
+                        }
+                        #[test]
+                        fn div_floor_positive(a in $base::MIN..0, b in 1..=$base::MAX) {
+                            let (x, y) = ($neg::new(a).unwrap(), $pos::new(b).unwrap());
+                            let q = x.div_floor_positive(y).get();
+                            let r = x.rem_positive(y).get();
+                            assert_eq!(q, a.div_euclid(b));
+                            assert_eq!(r, a.rem_euclid(b));
+                            assert_eq!(q.wrapping_mul(b).wrapping_add(r), a);
+                            assert_eq!((x / y).get(), a / b);
+                        }
+                        #[test]
+                        fn div_negative(a in $base::MIN..0, b in $base::MIN..0) {
+                            let (x, y) = ($neg::new(a).unwrap(), $neg::new(b).unwrap());
+                            if let Some(n) = a.checked_div(b) {
+                                assert_eq!((x / y).get(), n);
+                            }
+                        }
+                        #[test]
+                        fn rem_negative(a in 0..=$base::MAX, b in $base::MIN..0) {
+                            let (x, y) = ($pos::new(a).unwrap(), $neg::new(b).unwrap());
+                            assert_eq!((x % y).get(), a % b);
+                            let mut n = x;
+                            n %= y;
+                            assert_eq!(n.get(), a % b);
+                        }
+                    }
+                    #[test]
+                    #[should_panic = "attempt to divide by zero"]
+                    fn div_zero() {
+                        let _ = $neg::MIN / $pos::new(0).unwrap();
+                    }
+                    #[test]
+                    #[should_panic = "attempt to divide with overflow"]
+                    fn div_overflow() {
+                        let _ = $neg::MIN / $neg::MAX;
+                    }
+                    #[test]
+                    #[should_panic = "attempt to negate with overflow"]
+                    fn neg_overflow() {
+                        let _ = -$neg::MIN;
+                    }
+                    #[test]
+                    #[should_panic = "attempt to negate zero to a negative value"]
+                    fn neg_zero() {
+                        let _ = -$pos::new(0).unwrap();
+                    }
+                    #[test]
+                    #[cfg_attr(debug_assertions, should_panic = "attempt to multiply with overflow")]
+                    fn mul_overflow() {
+                        assert_eq!(($neg::MIN * $neg::MAX).get(), 0);
+                    }
+                    #[test]
+                    #[should_panic = "attempt to multiply a negative value by zero"]
+                    fn mul_positive_zero() {
+                        let _ = $neg::MAX * $pos::new(0).unwrap();
+                    }
+                    #[test]
+                    #[should_panic = "attempt to multiply a negative value by zero"]
+                    fn positive_zero_mul() {
+                        let mut n = $neg::MIN;
+                        n *= $pos::new(0).unwrap();
+                    }
+                }
+            }
+        };
+    }
+    fn generic_sum<T: SignBoundInt + core::ops::Add<Output = T>>(
+        values: &[Positive<T>],
+    ) -> Option<Positive<T>> {
+        values.iter().try_fold(Positive::MIN, |acc, v| Positive::new(acc.get() + v.get()))
+    }
+
+    #[test]
+    fn generic() {
+        assert_eq!(generic_sum(&[positive_i8!(1), positive_i8!(2)]), PositiveI8::new(3));
+        assert_eq!(generic_sum(&[positive_i64!(1), positive_i64!(2)]), PositiveI64::new(3));
+        assert_eq!(Positive::new(-1i32), None);
+        assert_eq!(Negative::new(-1i32), Some(NegativeI32::MAX));
+        assert_eq!(Positive::<i128>::BITS, i128::BITS);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::ops::{
-        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Not, Rem,
-        RemAssign,
-    };
-    use proptest::prelude::*;
+    test_type! { i8, u8, PositiveI8, NegativeI8 }
+    test_type! { i16, u16, PositiveI16, NegativeI16 }
+    test_type! { i32, u32, PositiveI32, NegativeI32 }
+    test_type! { i64, u64, PositiveI64, NegativeI64 }
+    test_type! { i128, u128, PositiveI128, NegativeI128 }
+    test_type! { isize, usize, PositiveIsize, NegativeIsize }
 
-    macro_rules! test_unary {
-        ($ty:ident, $base:ident ($range:expr) :: $($method:ident),+ $(,)?) => {
+    macro_rules! test_iter {
+        ($base:ident, $pos:ident, $neg:ident, $npos:ident, $wbase:ident) => {
+            mod $base {
+                use super::*;
+                proptest! {
+                    #[test]
+                    fn positive(values in prop::array::uniform8(0..=$base::MAX)) {
+                        let items = values.map(|n| $pos::new(n).unwrap());
+                        let sum = values.iter().try_fold(0 as $base, |a, &b| a.checked_add(b));
+                        let product = values.iter().try_fold(1 as $base, |a, &b| a.checked_mul(b));
+                        assert_eq!(items.into_iter().checked_sum().map(|n| n.get()), sum);
+                        assert_eq!(items.into_iter().saturating_sum().get(), sum.unwrap_or($base::MAX));
+                        assert_eq!(items.into_iter().checked_product().map(|n| n.get()), product);
+                        assert_eq!(
+                            $wbase::try_from(items.into_iter().wide_sum()).unwrap(),
+                            values.iter().map(|&n| n as $wbase).sum::<$wbase>(),
+                        );
+                        if let Some(sum) = sum {
+                            assert_eq!(items.into_iter().sum::<$pos>().get(), sum);
+                            assert_eq!(items.iter().sum::<$pos>().get(), sum);
+                        }
+                        if let Some(product) = product {
+                            assert_eq!(items.into_iter().product::<$pos>().get(), product);
+                            assert_eq!(items.iter().product::<$pos>().get(), product);
+                        }
+                    }
+                    #[test]
+                    fn negative(values in prop::array::uniform8($base::MIN..0)) {
+                        let items = values.map(|n| $neg::new(n).unwrap());
+                        if let Some(sum) = values.iter().try_fold(0 as $base, |a, &b| a.checked_add(b)) {
+                            assert_eq!(items.into_iter().sum::<$npos>().get(), sum);
+                            assert_eq!(items.iter().sum::<$npos>().get(), sum);
+                            assert_eq!(items.into_iter().sum::<$neg>().get(), sum);
+                            assert_eq!(items.iter().sum::<$neg>().get(), sum);
+                        }
+                    }
+                }
+                #[test]
+                fn empty() {
+                    assert_eq!(core::iter::empty::<$pos>().sum::<$pos>(), $pos::MIN);
+                    assert_eq!(core::iter::empty::<$pos>().product::<$pos>().get(), 1);
+                    assert_eq!(core::iter::empty::<$pos>().checked_product().map(|n| n.get()), Some(1));
+                    assert_eq!(core::iter::empty::<$neg>().sum::<$npos>().get(), 0);
+                }
+                #[test]
+                #[cfg_attr(debug_assertions, should_panic = "attempt to add with overflow")]
+                fn sum_overflow() {
+                    let _ = [$pos::MAX, $pos::MAX].into_iter().sum::<$pos>();
+                    let _ = [$neg::MIN, $neg::MIN].into_iter().sum::<$npos>();
+                    let _ = [$neg::MIN, $neg::MIN].into_iter().sum::<$neg>();
+                }
+                #[test]
+                #[should_panic = "attempt to sum an empty iterator into a negative value"]
+                fn sum_empty_negative() {
+                    let _ = core::iter::empty::<$neg>().sum::<$neg>();
+                }
+            }
+        };
+    }
+    mod iter {
+        use super::*;
+        test_iter! { i8, PositiveI8, NegativeI8, NonPositiveI8, i16 }
+        test_iter! { i16, PositiveI16, NegativeI16, NonPositiveI16, i32 }
+        test_iter! { i32, PositiveI32, NegativeI32, NonPositiveI32, i64 }
+        test_iter! { i64, PositiveI64, NegativeI64, NonPositiveI64, i128 }
+        test_iter! { isize, PositiveIsize, NegativeIsize, NonPositiveIsize, i128 }
+        #[test]
+        fn wide_sum_saturating() {
+            let sum = [PositiveI8::MAX; 300].into_iter().wide_sum();
+            assert_eq!(sum, PositiveI16::MAX);
+            let sum = [PositiveI8::MAX; 258].into_iter().wide_sum();
+            assert_eq!(sum.get(), 258 * 127);
+        }
+        #[test]
+        fn wide_sum_i128() {
+            let (lo, hi) = [PositiveI128::MAX; 4].into_iter().wide_sum();
+            assert_eq!((lo, hi.get()), (u128::MAX - 3, 1));
+        }
+    }
+
+    macro_rules! test_widening_mul {
+        ($base:ident, $pos:ident, $neg:ident, $wbase:ident) => {
+            mod $base {
+                use super::*;
+                proptest! {
+                    #[test]
+                    fn positive(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                        let (x, y) = ($pos::new(a).unwrap(), $pos::new(b).unwrap());
+                        assert_eq!(x.widening_mul(y).get(), a as $wbase * b as $wbase);
+                    }
+                    #[test]
+                    fn negative(a in $base::MIN..0, b in $base::MIN..0) {
+                        let (x, y) = ($neg::new(a).unwrap(), $neg::new(b).unwrap());
+                        assert_eq!(x.widening_mul(y).get(), a as $wbase * b as $wbase);
+                    }
+                    #[test]
+                    fn mixed(a in 0..=$base::MAX, b in $base::MIN..0) {
+                        let (x, y) = ($pos::new(a).unwrap(), $neg::new(b).unwrap());
+                        assert_eq!(x.widening_mul_negative(y).get(), a as $wbase * b as $wbase);
+                        assert_eq!(y.widening_mul_positive(x).get(), a as $wbase * b as $wbase);
+                    }
+                }
+            }
+        };
+    }
+    mod widening_mul {
+        use super::*;
+        test_widening_mul! { i8, PositiveI8, NegativeI8, i16 }
+        test_widening_mul! { i16, PositiveI16, NegativeI16, i32 }
+        test_widening_mul! { i32, PositiveI32, NegativeI32, i64 }
+        test_widening_mul! { i64, PositiveI64, NegativeI64, i128 }
+        mod i128 {
+            use super::*;
+            fn reference(a: i128, b: i128) -> (u128, i128) {
+                let (x, y) = (a.unsigned_abs(), b.unsigned_abs());
+                let mut limbs = [0u64; 8];
+                for i in 0..4 {
+                    for j in 0..4 {
+                        let mut carry = (x >> (32 * i)) as u32 as u64 * (y >> (32 * j)) as u32 as u64;
+                        let mut k = i + j;
+                        while carry != 0 {
+                            let t = limbs[k] + (carry & u32::MAX as u64);
+                            limbs[k] = t & u32::MAX as u64;
+                            carry = (carry >> 32) + (t >> 32);
+                            k += 1;
+                        }
+                    }
+                }
+                let join = |l: &[u64]| l.iter().rev().fold(0u128, |acc, &n| acc << 32 | n as u128);
+                let (lo, hi) = (join(&limbs[..4]), join(&limbs[4..]));
+                if (a < 0) != (b < 0) {
+                    let lo2 = (!lo).wrapping_add(1);
+                    (lo2, (!hi).wrapping_add((lo2 == 0) as u128) as i128)
+                } else {
+                    (lo, hi as i128)
+                }
+            }
             proptest! {
-                $(#[test] fn $method(n in $range) {
-                    assert_eq!($ty::new(n).map(|n| n.$method()), Some((n as $base).$method()));
-                })+
+                #[test]
+                fn positive(a in 0..=i128::MAX, b in 0..=i128::MAX, c in 0..=i128::MAX) {
+                    let (x, y, z) = (PositiveI128::new(a).unwrap(), PositiveI128::new(b).unwrap(), PositiveI128::new(c).unwrap());
+                    let (lo, hi) = x.widening_mul(y);
+                    assert_eq!((lo, hi.get()), reference(a, b));
+                    let (clo, chi) = x.carrying_mul(y, z);
+                    let (elo, carry) = lo.overflowing_add(c as u128);
+                    assert_eq!((clo, chi.get()), (elo, hi.get() + carry as i128));
+                }
+                #[test]
+                fn negative(a in i128::MIN..0, b in i128::MIN..0) {
+                    let (x, y) = (NegativeI128::new(a).unwrap(), NegativeI128::new(b).unwrap());
+                    let (lo, hi) = x.widening_mul(y);
+                    assert_eq!((lo, hi.get()), reference(a, b));
+                    assert_eq!(lo, a.wrapping_mul(b) as u128);
+                }
+                #[test]
+                fn mixed(a in 0..=i128::MAX, b in i128::MIN..0) {
+                    let (x, y) = (PositiveI128::new(a).unwrap(), NegativeI128::new(b).unwrap());
+                    let (lo, hi) = x.widening_mul_negative(y);
+                    assert_eq!((lo, hi.get()), reference(a, b));
+                    assert_eq!(lo, a.wrapping_mul(b) as u128);
+                    assert_eq!(y.widening_mul_positive(x), (lo, hi));
+                    if let Some(n) = a.checked_mul(b) {
+                        assert_eq!(hi.get(), n >> 127);
+                    }
+                }
+            }
+            #[test]
+            fn extremes() {
+                let (lo, hi) = PositiveI128::MAX.widening_mul(PositiveI128::MAX);
+                assert_eq!((lo, hi.get()), (1, i128::MAX >> 1));
+                let (lo, hi) = NegativeI128::MIN.widening_mul(NegativeI128::MIN);
+                assert_eq!((lo, hi.get()), (0, 1 << 126));
+                let (lo, hi) = PositiveI128::MAX.widening_mul_negative(NegativeI128::MIN);
+                assert_eq!((lo, hi.get()), (1 << 127, -(1 << 126)));
+                let (lo, hi) = PositiveI128::MIN.widening_mul_negative(NegativeI128::MIN);
+                assert_eq!((lo, hi.get()), (0, 0));
+            }
+        }
+    }
+
+    macro_rules! test_sign_either {
+        ($base:ident, $ty:ident, $pos:ident, $neg:ident) => {
+            mod $base {
+                use super::*;
+                proptest! {
+                    #[test]
+                    fn classify(n in $base::MIN..=$base::MAX) {
+                        let value = $ty::new(n);
+                        assert_eq!(value.get(), n);
+                        match value.classify() {
+                            Either::Positive(p) => assert_eq!(p.get(), n),
+                            Either::Negative(m) => assert_eq!(m.get(), n),
+                        }
+                        assert_eq!(value.positive(), $pos::new(n));
+                        assert_eq!(value.negative(), $neg::new(n));
+                        assert_eq!($ty::from(value.classify()), value);
+                    }
+                    #[test]
+                    fn split(n in $base::MIN..=$base::MAX) {
+                        let split = split_sign(n);
+                        assert_eq!(split.get(), n);
+                        assert_eq!(split.sign(), Sign::classify(n));
+                        assert_eq!(split.sign() == Sign::Negative, n < 0);
+                        match split {
+                            SignSplit::NonNegative(p) => assert_eq!(Some(p), $pos::new(n)),
+                            SignSplit::Negative(m) => assert_eq!(Some(m), $neg::new(n)),
+                        }
+                        assert_eq!($ty::new(n).sign(), split.sign());
+                    }
+                    #[test]
+                    fn map_positive(n in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            $ty::new(n).map_positive(|_| $pos::MAX).get(),
+                            if n < 0 { n } else { $base::MAX },
+                        );
+                    }
+                    #[test]
+                    fn map_negative(n in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            $ty::new(n).map_negative(|_| $neg::MIN).get(),
+                            if n < 0 { $base::MIN } else { n },
+                        );
+                    }
+                }
+                #[test]
+                fn layout() {
+                    assert_eq!(size_of::<$ty>(), size_of::<$base>());
+                }
             }
         };
     }
+    mod sign_either {
+        use super::*;
+        test_sign_either! { i8, SignEitherI8, PositiveI8, NegativeI8 }
+        test_sign_either! { i16, SignEitherI16, PositiveI16, NegativeI16 }
+        test_sign_either! { i32, SignEitherI32, PositiveI32, NegativeI32 }
+        test_sign_either! { i64, SignEitherI64, PositiveI64, NegativeI64 }
+        test_sign_either! { i128, SignEitherI128, PositiveI128, NegativeI128 }
+        test_sign_either! { isize, SignEitherIsize, PositiveIsize, NegativeIsize }
+    }
 
-    macro_rules! test_unary_op {
-        ($ty:ident, $base:ident ($range:expr) :: $($method:ident),+ $(,)?) => {
-            proptest! {
-                $(#[test] fn $method(n in $range) {
-                    assert_eq!($ty::new(n).map(|n| n.$method().get()), Some((n as $base).$method()));
-                })+
+    macro_rules! test_atomic {
+        ($base:ident, $pos:ident, $neg:ident, $apos:ident, $aneg:ident, $aopt:ident) => {
+            mod $base {
+                use super::*;
+                use core::sync::atomic::Ordering::Relaxed;
+                proptest! {
+                    #[test]
+                    fn positive(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                        let (pa, pb) = ($pos::new(a).unwrap(), $pos::new(b).unwrap());
+                        let atomic = $apos::new(pa);
+                        assert_eq!(atomic.load(Relaxed), pa);
+                        assert_eq!(atomic.fetch_saturating_add(pb, Relaxed, Relaxed), pa);
+                        assert_eq!(atomic.load(Relaxed).get(), a.saturating_add(b));
+                        atomic.store(pa, Relaxed);
+                        assert_eq!(atomic.fetch_saturating_sub(pb, Relaxed, Relaxed), pa);
+                        assert_eq!(atomic.load(Relaxed).get(), a.saturating_sub(b).max(0));
+                        atomic.store(pa, Relaxed);
+                        let added = atomic.fetch_checked_add(pb, Relaxed, Relaxed);
+                        assert_eq!(added.is_ok(), a.checked_add(b).is_some());
+                        assert_eq!(atomic.swap(pa, Relaxed).get(), a.checked_add(b).unwrap_or(a));
+                        let subbed = atomic.fetch_checked_sub(pb, Relaxed, Relaxed);
+                        assert_eq!(subbed.is_ok(), a >= b);
+                        atomic.store(pa, Relaxed);
+                        assert_eq!(atomic.fetch_max(pb, Relaxed), pa);
+                        assert_eq!(atomic.load(Relaxed).get(), a.max(b));
+                        atomic.store(pa, Relaxed);
+                        assert_eq!(atomic.fetch_min(pb, Relaxed), pa);
+                        assert_eq!(atomic.load(Relaxed).get(), a.min(b));
+                        assert_eq!(atomic.compare_exchange(pa, pb, Relaxed, Relaxed).is_ok(), a == b.min(a));
+                        assert_eq!(atomic.into_inner().get(), if a <= b { b } else { a.min(b) });
+                    }
+                    #[test]
+                    fn negative(a in $base::MIN..0, b in $base::MIN..0) {
+                        let (na, nb) = ($neg::new(a).unwrap(), $neg::new(b).unwrap());
+                        let atomic = $aneg::new(na);
+                        assert_eq!(atomic.fetch_saturating_add(nb, Relaxed, Relaxed), na);
+                        assert_eq!(atomic.load(Relaxed).get(), a.saturating_add(b));
+                        atomic.store(na, Relaxed);
+                        assert_eq!(atomic.fetch_saturating_sub(nb, Relaxed, Relaxed), na);
+                        assert_eq!(atomic.load(Relaxed).get(), (a - b).min(-1));
+                        atomic.store(na, Relaxed);
+                        let added = atomic.fetch_checked_add(nb, Relaxed, Relaxed);
+                        assert_eq!(added.is_ok(), a.checked_add(b).is_some());
+                        atomic.store(na, Relaxed);
+                        let subbed = atomic.fetch_checked_sub(nb, Relaxed, Relaxed);
+                        assert_eq!(subbed.is_ok(), a < b);
+                        atomic.store(na, Relaxed);
+                        assert_eq!(atomic.fetch_max(nb, Relaxed), na);
+                        assert_eq!(atomic.load(Relaxed).get(), a.max(b));
+                        atomic.store(na, Relaxed);
+                        assert_eq!(atomic.fetch_min(nb, Relaxed), na);
+                        assert_eq!(atomic.into_inner().get(), a.min(b));
+                    }
+                    #[test]
+                    fn option(a in $base::MIN..=$base::MAX, b in $base::MIN..=$base::MAX) {
+                        let (pa, pb) = ($pos::new(a), $pos::new(b));
+                        let atomic = $aopt::new(pa);
+                        assert_eq!(atomic.load(Relaxed), pa);
+                        assert_eq!(atomic.swap(pb, Relaxed), pa);
+                        assert_eq!(atomic.compare_exchange(pa, pb, Relaxed, Relaxed).is_ok(), pa == pb);
+                        assert_eq!(atomic.compare_exchange(pb, pa, Relaxed, Relaxed), Ok(pb));
+                        assert_eq!(atomic.take(Relaxed), pa);
+                        assert_eq!(atomic.load(Relaxed), None);
+                        assert_eq!(atomic.fetch_update(Relaxed, Relaxed, |_| Some(pb)), Ok(None));
+                        assert_eq!(atomic.into_inner(), pb);
+                    }
+                }
+                #[test]
+                fn layout() {
+                    assert_eq!(size_of::<$apos>(), size_of::<$base>());
+                    assert_eq!(size_of::<$aneg>(), size_of::<$base>());
+                    assert_eq!(size_of::<$aopt>(), size_of::<$base>());
+                    assert_eq!($aopt::default().into_inner(), None);
+                }
             }
         };
     }
+    macro_rules! test_optional_index {
+        ($base:ident, $ty:ident, $pty:ident) => {
+            mod $base {
+                use super::*;
+                proptest! {
+                    #[test]
+                    fn roundtrip(n in -1..=$base::MAX) {
+                        let index = $ty::from_raw(n).unwrap();
+                        assert_eq!(index.to_raw(), n);
+                        assert_eq!(index.get(), $pty::new(n));
+                        assert_eq!($ty::new(index.get()), index);
+                        assert_eq!(index.to_usize(), usize::try_from(n).ok());
+                        assert_eq!(index.is_none(), n == -1);
+                        if let Some(u) = index.to_usize() {
+                            assert_eq!($ty::from_usize(u), Some(index));
+                        }
+                    }
+                    #[test]
+                    fn from_raw(n in $base::MIN..=$base::MAX) {
+                        assert_eq!($ty::from_raw(n).is_some(), n >= -1);
+                    }
+                    #[test]
+                    fn from_usize(n: usize) {
+                        assert_eq!(
+                            $ty::from_usize(n).map(|i| i.to_raw()),
+                            $base::try_from(n).ok(),
+                        );
+                    }
+                    #[test]
+                    fn get_in(n in -1..=(8 as $base), len in 0..8usize) {
+                        let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+                        let index = $ty::from_raw(n).unwrap();
+                        let expected = usize::try_from(n).ok().filter(|&n| n < len);
+                        assert_eq!(index.get_in(&array[..len]).copied(), expected);
+                        assert_eq!(index.get_mut_in(&mut array[..len]).copied(), expected);
+                    }
+                    #[test]
+                    fn take_replace(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                        let (a, b) = ($pty::new(a).unwrap(), $pty::new(b).unwrap());
+                        let mut index = $ty::from(a);
+                        assert_eq!(index.replace(b), $ty::from(a));
+                        assert_eq!(index.take().get(), Some(b));
+                        assert_eq!(index, $ty::NONE);
+                        assert_eq!(index.take(), $ty::NONE);
+                    }
+                }
+                #[test]
+                fn none() {
+                    assert_eq!($ty::NONE.to_raw(), -1);
+                    assert_eq!($ty::default(), $ty::NONE);
+                    assert_eq!($ty::new(None).to_raw(), -1);
+                    assert_eq!(Option::<$pty>::from($ty::NONE), None);
+                    assert_eq!(size_of::<$ty>(), size_of::<$base>());
+                }
+            }
+        };
+    }
+    mod optional_index {
+        use super::*;
+        test_optional_index! { isize, OptionalIndex, PositiveIsize }
+        test_optional_index! { i32, OptionalIndex32, PositiveI32 }
+        test_optional_index! { i16, OptionalIndex16, PositiveI16 }
+    }
 
-    macro_rules! test_unary_checked {
-        ($ty:ident, $base:ident ($range:expr) :: $($method:ident),+ $(,)?) => {
-            proptest! {
-                $(#[test] fn $method(n in $range) {
-                    assert_eq!($ty::new(n).and_then(|n| n.$method()), (n as $base).$method());
-                })+
+    mod atomic {
+        use super::*;
+        test_atomic! { i8, PositiveI8, NegativeI8, AtomicPositiveI8, AtomicNegativeI8, AtomicOptionPositiveI8 }
+        test_atomic! { i16, PositiveI16, NegativeI16, AtomicPositiveI16, AtomicNegativeI16, AtomicOptionPositiveI16 }
+        test_atomic! { i32, PositiveI32, NegativeI32, AtomicPositiveI32, AtomicNegativeI32, AtomicOptionPositiveI32 }
+        test_atomic! { i64, PositiveI64, NegativeI64, AtomicPositiveI64, AtomicNegativeI64, AtomicOptionPositiveI64 }
+        test_atomic! { isize, PositiveIsize, NegativeIsize, AtomicPositiveIsize, AtomicNegativeIsize, AtomicOptionPositiveIsize }
+    }
+
+    macro_rules! test_unsigned {
+        ($uns:ident, $ty:ident, $pos:ident) => {
+            mod $uns {
+                use super::*;
+                const MASK: $uns = $uns::MAX >> 1;
+                proptest! {
+                    #[test]
+                    fn valid(n in 0..=MASK) {
+                        assert_eq!($ty::new(n).map(|n| n.get()), Some(n));
+                    }
+                    #[test]
+                    fn invalid(n in MASK + 1..=$uns::MAX) {
+                        assert_eq!($ty::new(n).map(|n| n.get()), None);
+                    }
+                    #[test]
+                    fn positive(n in 0..=MASK) {
+                        let p = $pos::try_from(n).unwrap();
+                        assert_eq!($ty::from(p).get(), n);
+                        assert_eq!($pos::from($ty::new(n).unwrap()), p);
+                    }
+                    #[test]
+                    fn wrapping_add(a in 0..=MASK, b in 0..=MASK) {
+                        assert_eq!(
+                            $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.wrapping_add(b).get()),
+                            Some(a.wrapping_add(b) & MASK),
+                        );
+                    }
+                    #[test]
+                    fn wrapping_sub(a in 0..=MASK, b in 0..=MASK) {
+                        assert_eq!(
+                            $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.wrapping_sub(b).get()),
+                            Some(a.wrapping_sub(b) & MASK),
+                        );
+                    }
+                    #[test]
+                    fn wrapping_mul(a in 0..=MASK, b in 0..=MASK) {
+                        assert_eq!(
+                            $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.wrapping_mul(b).get()),
+                            Some(a.wrapping_mul(b) & MASK),
+                        );
+                    }
+                    #[test]
+                    fn wrapping_neg(n in 0..=MASK) {
+                        assert_eq!($ty::new(n).map(|n| n.wrapping_neg().get()), Some(n.wrapping_neg() & MASK));
+                    }
+                    #[test]
+                    fn wrapping_pow(a in 0..=MASK, b in 0..u32::MAX) {
+                        assert_eq!($ty::new(a).map(|a| a.wrapping_pow(b).get()), Some(a.wrapping_pow(b) & MASK));
+                    }
+                    #[test]
+                    fn checked_add(a in 0..=MASK, b in 0..=MASK) {
+                        assert_eq!(
+                            $ty::new(a).zip($ty::new(b)).and_then(|(a, b)| a.checked_add(b)).map(|n| n.get()),
+                            a.checked_add(b).filter(|n| *n <= MASK),
+                        );
+                    }
+                    #[test]
+                    fn checked_sub(a in 0..=MASK, b in 0..=MASK) {
+                        assert_eq!(
+                            $ty::new(a).zip($ty::new(b)).and_then(|(a, b)| a.checked_sub(b)).map(|n| n.get()),
+                            a.checked_sub(b),
+                        );
+                    }
+                    #[test]
+                    fn saturating_add(a in 0..=MASK, b in 0..=MASK) {
+                        assert_eq!(
+                            $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.saturating_add(b).get()),
+                            Some(a.saturating_add(b).min(MASK)),
+                        );
+                    }
+                    #[test]
+                    fn leading_zeros(n in 0..=MASK) {
+                        assert_eq!($ty::new(n).map(|n| n.leading_zeros()), Some(n.leading_zeros() - 1));
+                    }
+                }
+                #[test]
+                fn layout() {
+                    assert_eq!($ty::BITS, $uns::BITS - 1);
+                    assert_eq!(size_of::<Option<$ty>>(), size_of::<$uns>());
+                }
             }
         };
     }
+    mod unsigned {
+        use super::*;
+        test_unsigned! { u8, U7, PositiveI8 }
+        test_unsigned! { u16, U15, PositiveI16 }
+        test_unsigned! { u32, U31, PositiveI32 }
+        test_unsigned! { u64, U63, PositiveI64 }
+        test_unsigned! { u128, U127, PositiveI128 }
+        test_unsigned! { usize, USizeHalf, PositiveIsize }
+    }
 
-    macro_rules! test_binary {
-        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident),+ $(,)?) => {
-            proptest! {
-                $(#[test] fn $method(a in $range1, b in $range2) {
-                    assert_eq!(
-                        $ty::new(a).zip($ty::new(b)).map(|(a, b)| a.$method(b)).map(|a| a.get()),
-                        Some((a as $base).$method(b as $base)),
-                    );
-                })+
+    macro_rules! test_bounded {
+        ($name:ident, $ty:ident, $base:ident, $min:expr, $max:expr) => {
+            mod $name {
+                use super::*;
+                const MIN: $base = $min;
+                const MAX: $base = $max;
+                type T = $ty<MIN, MAX>;
+                proptest! {
+                    #[test]
+                    fn new(n in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(n).map(|n| n.get()),
+                            Some(n).filter(|n| (MIN..=MAX).contains(n)),
+                        );
+                    }
+                    #[test]
+                    fn new_saturating(n in $base::MIN..=$base::MAX) {
+                        assert_eq!(T::new_saturating(n).get(), n.clamp(MIN, MAX));
+                    }
+                    #[test]
+                    fn checked_add(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).and_then(|a| a.checked_add(b)).map(|n| n.get()),
+                            a.checked_add(b).filter(|n| (MIN..=MAX).contains(n)),
+                        );
+                    }
+                    #[test]
+                    fn checked_sub(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).and_then(|a| a.checked_sub(b)).map(|n| n.get()),
+                            a.checked_sub(b).filter(|n| (MIN..=MAX).contains(n)),
+                        );
+                    }
+                    #[test]
+                    fn checked_mul(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).and_then(|a| a.checked_mul(b)).map(|n| n.get()),
+                            a.checked_mul(b).filter(|n| (MIN..=MAX).contains(n)),
+                        );
+                    }
+                    #[test]
+                    fn checked_div(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).and_then(|a| a.checked_div(b)).map(|n| n.get()),
+                            a.checked_div(b).filter(|n| (MIN..=MAX).contains(n)),
+                        );
+                    }
+                    #[test]
+                    fn saturating_add(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).map(|a| a.saturating_add(b).get()),
+                            Some(a.saturating_add(b).clamp(MIN, MAX)),
+                        );
+                    }
+                    #[test]
+                    fn saturating_sub(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).map(|a| a.saturating_sub(b).get()),
+                            Some(a.saturating_sub(b).clamp(MIN, MAX)),
+                        );
+                    }
+                    #[test]
+                    fn saturating_mul(a in MIN..=MAX, b in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            T::new(a).map(|a| a.saturating_mul(b).get()),
+                            Some(a.saturating_mul(b).clamp(MIN, MAX)),
+                        );
+                    }
+                }
             }
         };
     }
+    mod bounded {
+        use super::*;
+        test_bounded! { i8_full, BoundedI8, i8, i8::MIN, i8::MAX }
+        test_bounded! { i8_mixed, BoundedI8, i8, -90, 90 }
+        test_bounded! { i8_positive, BoundedPositiveI8, i8, 0, 100 }
+        test_bounded! { i8_negative, BoundedNegativeI8, i8, -100, -1 }
+        test_bounded! { i16_mixed, BoundedI16, i16, -90, 90 }
+        test_bounded! { i16_positive, BoundedPositiveI16, i16, 0, 1000 }
+        test_bounded! { i16_negative, BoundedNegativeI16, i16, -1000, -1 }
+        test_bounded! { i32_mixed, BoundedI32, i32, -90, 90 }
+        test_bounded! { i32_positive, BoundedPositiveI32, i32, 10, 1000 }
+        test_bounded! { i32_negative, BoundedNegativeI32, i32, -1000, -10 }
+        test_bounded! { i64_mixed, BoundedI64, i64, i64::MIN, 0 }
+        test_bounded! { i64_positive, BoundedPositiveI64, i64, 0, i64::MAX }
+        test_bounded! { i64_negative, BoundedNegativeI64, i64, i64::MIN, -1 }
 
-    macro_rules! test_binary_checked {
-        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident),+ $(,)?) => {
-            proptest! {
-                $(#[test] fn $method(a in $range1, b in $range2) {
-                    assert_eq!(
-                        $ty::new(a).zip($ty::new(b)).and_then(|(a, b)| a.$method(b)).map(|a| a.get()),
-                        (a as $base).$method(b as $base),
-                    );
-                })+
+        #[test]
+        fn layout() {
+            assert_eq!(size_of::<Option<BoundedPositiveI32<0, 1000>>>(), size_of::<i32>());
+            assert_eq!(size_of::<Option<BoundedNegativeI32<-1000, -1>>>(), size_of::<i32>());
+            assert_eq!(size_of::<BoundedI32<-90, 90>>(), size_of::<i32>());
+        }
+    }
+
+    macro_rules! test_non_positive {
+        ($base:ident, $np:ident, $pos:ident, $neg:ident) => {
+            mod $base {
+                use super::*;
+                proptest! {
+                    #[test]
+                    fn valid(n in $base::MIN..=0) {
+                        assert_eq!($np::new(n).map(|n| n.get()), Some(n));
+                    }
+                    #[test]
+                    fn invalid(n in 1..=$base::MAX) {
+                        assert_eq!($np::new(n).map(|n| n.get()), None);
+                    }
+                    #[test]
+                    fn from_neg(n in 0..=$base::MAX) {
+                        assert_eq!($pos::new(n).map(|n| $np::from_neg(n).get()), Some(-n));
+                    }
+                    #[test]
+                    fn checked_neg(n in $base::MIN..=0) {
+                        assert_eq!(
+                            $np::new(n).and_then(|n| n.checked_neg()).map(|n| n.get()),
+                            n.checked_neg(),
+                        );
+                    }
+                    #[test]
+                    fn checked_sub(a in $base::MIN..=0, b in $base::MIN..=0) {
+                        assert_eq!(
+                            $np::new(a).zip($np::new(b)).and_then(|(a, b)| a.checked_sub(b)),
+                            a.checked_sub(b).and_then($np::new),
+                        );
+                    }
+                    #[test]
+                    fn checked_mul_positive(a in $base::MIN..=0, b in 0..=$base::MAX) {
+                        assert_eq!(
+                            $np::new(a)
+                                .zip($pos::new(b))
+                                .and_then(|(a, b)| a.checked_mul_positive(b))
+                                .map(|n| n.get()),
+                            a.checked_mul(b),
+                        );
+                    }
+                    #[test]
+                    fn checked_div(a in $base::MIN..=0, b in $base::MIN..0) {
+                        assert_eq!(
+                            $np::new(a).zip($neg::new(b)).and_then(|(a, b)| a.checked_div(b)).map(|n| n.get()),
+                            a.checked_div(b),
+                        );
+                    }
+                    #[test]
+                    fn saturating_sub(a in $base::MIN..=0, b in $base::MIN..=0) {
+                        assert_eq!(
+                            $np::new(a).zip($np::new(b)).map(|(a, b)| a.saturating_sub(b).get()),
+                            Some(a.saturating_sub(b).min(0)),
+                        );
+                    }
+                    #[test]
+                    fn div_negative(a in 0..=$base::MAX, b in $base::MIN..0) {
+                        assert_eq!(
+                            $pos::new(a).zip($neg::new(b)).map(|(a, b)| (a / b).get()),
+                            Some(a / b),
+                        );
+                    }
+                    #[test]
+                    fn rem_negative(a in $base::MIN..0, b in $base::MIN..0) {
+                        assert_eq!(
+                            $neg::new(a).zip($neg::new(b)).map(|(a, b)| (a % b).get()),
+                            Some(a.wrapping_rem(b)),
+                        );
+                    }
+                    #[test]
+                    fn sub_extremes(a in $base::MIN..=0, b in $base::MIN..=0) {
+                        let (x, y) = ($np::new(a).unwrap(), $np::new(b).unwrap());
+                        assert_eq!(x.checked_sub(y).map(|n| n.get()), a.checked_sub(b).filter(|n| *n <= 0));
+                        assert_eq!(x.saturating_sub(y).get(), a.saturating_sub(b).min(0));
+                    }
+                    #[test]
+                    fn negative(n in $base::MIN..=0) {
+                        assert_eq!(
+                            $np::new(n).and_then(|n| $neg::try_from(n).ok()),
+                            $neg::new(n),
+                        );
+                    }
+                }
+                test_unary! { $np, $base ($base::MIN..=0)
+                :: count_zeros, count_ones, leading_zeros, trailing_zeros, signum }
+                test_binary_checked! { $np, $base ($base::MIN..=0, $base::MIN..=0)
+                :: checked_add, checked_mul }
+                test_binary! { $np, $base ($base::MIN..=0, $base::MIN..=0)
+                :: saturating_add, saturating_mul, bitor }
+                #[test]
+                fn rem_min() {
+                    assert_eq!(($neg::MIN % $neg::MAX).get(), 0);
+                    assert_eq!(($neg::MIN % $neg::MIN).get(), 0);
+                    assert_eq!(($pos::MAX / $neg::MAX).get(), -$base::MAX);
+                }
+                #[test]
+                fn sub_min() {
+                    assert_eq!($np::MAX.checked_sub($np::MIN), None);
+                    assert_eq!($np::MAX.saturating_sub($np::MIN), $np::MAX);
+                    assert_eq!($np::MIN.checked_sub($np::MIN), $np::new(0));
+                }
+                #[test]
+                fn size() {
+                    assert_eq!(size_of::<Option<$np>>(), size_of::<$base>());
+                }
             }
         };
     }
+    mod non_positive {
+        use super::*;
+        test_non_positive! { i8, NonPositiveI8, PositiveI8, NegativeI8 }
+        test_non_positive! { i16, NonPositiveI16, PositiveI16, NegativeI16 }
+        test_non_positive! { i32, NonPositiveI32, PositiveI32, NegativeI32 }
+        test_non_positive! { i64, NonPositiveI64, PositiveI64, NegativeI64 }
+        test_non_positive! { i128, NonPositiveI128, PositiveI128, NegativeI128 }
+        test_non_positive! { isize, NonPositiveIsize, PositiveIsize, NegativeIsize }
+    }
 
-    macro_rules! test_assign {
-        ($ty:ident, $base:ident ($range1:expr, $range2:expr) :: $($method:ident),+ $(,)?) => {
-            proptest! {
-                $(#[test] fn $method(a in $range1, b in $range2) {
-                    let mut a1 = $ty::new(a);
-                    let mut a2 = a;
-                    if let Some((a, b)) = a1.as_mut().zip($ty::new(b)) {
-                        a.$method(b);
+    macro_rules! test_strictly_positive {
+        ($base:ident, $uns:ident, $sp:ident, $pos:ident) => {
+            mod $base {
+                use super::*;
+                proptest! {
+                    #[test]
+                    fn valid(n in 1..=$base::MAX) {
+                        assert_eq!($sp::new(n).map(|n| n.get()), Some(n));
                     }
-                    a2.$method(b);
-                    assert_eq!(a1.map(|a| a.get()), Some(a2));
-                })+
+                    #[test]
+                    fn invalid(n in $base::MIN..=0) {
+                        assert_eq!($sp::new(n).map(|n| n.get()), None);
+                    }
+                    #[test]
+                    fn checked_sub(a in 1..=$base::MAX, b in 1..=$base::MAX) {
+                        assert_eq!(
+                            $sp::new(a).zip($sp::new(b)).and_then(|(a, b)| a.checked_sub(b)),
+                            a.checked_sub(b).and_then($sp::new),
+                        );
+                    }
+                    #[test]
+                    fn div(a in 1..=$base::MAX, b in 1..=$base::MAX) {
+                        assert_eq!(
+                            $sp::new(a).zip($sp::new(b)).map(|(a, b)| a.div_strictly_positive(b).get()),
+                            Some(a / b),
+                        );
+                        assert_eq!(
+                            $sp::new(a).zip($sp::new(b)).map(|(a, b)| ($pos::from(a) / b).get()),
+                            Some(a / b),
+                        );
+                    }
+                    #[test]
+                    fn div_strictly_positive(a in 0..=$base::MAX, b in 1..=$base::MAX) {
+                        assert_eq!(
+                            $pos::new(a).zip($sp::new(b)).map(|(a, b)| (a / b).get()),
+                            Some(a / b),
+                        );
+                    }
+                    #[test]
+                    fn rem_strictly_positive(a in 0..=$base::MAX, b in 1..=$base::MAX) {
+                        assert_eq!(
+                            $pos::new(a).zip($sp::new(b)).map(|(a, b)| (a % b).get()),
+                            Some(a % b),
+                        );
+                    }
+                    #[test]
+                    fn non_zero(n in $base::MIN..=$base::MAX) {
+                        assert_eq!(
+                            core::num::NonZero::new(n).and_then(|n| $sp::try_from(n).ok()),
+                            $sp::new(n),
+                        );
+                        assert_eq!(
+                            $sp::new(n).map(core::num::NonZero::<$base>::from),
+                            core::num::NonZero::new(n).filter(|n| n.get() > 0),
+                        );
+                    }
+                    #[test]
+                    fn positive(n in 0..=$base::MAX) {
+                        assert_eq!($pos::new(n).and_then(|n| $sp::try_from(n).ok()), $sp::new(n));
+                    }
+                }
+                test_unary! { $sp, $base (1..=$base::MAX)
+                :: count_zeros, count_ones, leading_zeros, trailing_zeros, ilog2, ilog10, signum }
+                test_unary! { $sp, $uns (1..=$base::MAX) :: is_power_of_two }
+                test_binary_checked! { $sp, $base (1..=$base::MAX, 1..=$base::MAX)
+                :: checked_add, checked_mul }
+                test_binary! { $sp, $base (1..=$base::MAX, 1..=$base::MAX)
+                :: saturating_add, saturating_mul }
             }
         };
     }
+    mod strictly_positive {
+        use super::*;
+        test_strictly_positive! { i8, u8, StrictlyPositiveI8, PositiveI8 }
+        test_strictly_positive! { i16, u16, StrictlyPositiveI16, PositiveI16 }
+        test_strictly_positive! { i32, u32, StrictlyPositiveI32, PositiveI32 }
+        test_strictly_positive! { i64, u64, StrictlyPositiveI64, PositiveI64 }
+        test_strictly_positive! { i128, u128, StrictlyPositiveI128, PositiveI128 }
+        test_strictly_positive! { isize, usize, StrictlyPositiveIsize, PositiveIsize }
+    }
 
-    macro_rules! test_type {
-        ($base:ident, $uns:ident, $pos:ident, $neg:ident) => {
+    macro_rules! test_float {
+        ($base:ident, $pos:ident, $neg:ident) => {
             mod $base {
                 use super::*;
+                use core::ops::{Add, Neg, Sub};
+                use proptest::num::$base::{INFINITE, NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+                fn positive_values() -> impl Strategy<Value = $base> {
+                    POSITIVE | NORMAL | SUBNORMAL | ZERO | INFINITE
+                }
+                fn negative_values() -> impl Strategy<Value = $base> {
+                    NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE
+                }
                 mod positive {
                     use super::*;
                     proptest! {
                         #[test]
-                        fn valid(n in 0..=$base::MAX) {
+                        fn valid(n in positive_values()) {
                             assert_eq!($pos::new(n).map(|n| n.get()), Some(n));
                         }
                         #[test]
-                        fn invalid(n in $base::MIN..-1) {
+                        fn invalid(n in negative_values()) {
                             assert_eq!($pos::new(n).map(|n| n.get()), None);
                         }
                         #[test]
-                        fn checked_neg(n in 0..=$base::MAX) {
+                        fn cmp(a in positive_values(), b in positive_values()) {
                             assert_eq!(
-                                $pos::new(n).and_then(|n| n.checked_neg()),
-                                n.checked_neg().and_then($neg::new),
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.cmp(&b)),
+                                a.partial_cmp(&b),
                             );
                         }
                         #[test]
-                        fn checked_sub(a in 0..=$base::MAX, b in 0..=$base::MAX) {
+                        fn add(a in positive_values(), b in positive_values()) {
                             assert_eq!(
-                                $pos::new(a).zip($pos::new(b)).and_then(|(a, b)| a.checked_sub(b)),
-                                a.checked_sub(b).and_then($pos::new),
+                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.add(b).get()),
+                                Some(a + b),
                             );
                         }
                         #[test]
-                        fn checked_div_unsigned(a in 0..=$base::MAX, b in 0..=$uns::MAX) {
+                        fn sub(a in positive_values(), b in negative_values()) {
                             assert_eq!(
-                                $pos::new(a).and_then(|a| a.checked_div_unsigned(b)),
-                                (a as $uns).checked_div(b).and_then(|n| $pos::try_from(n).ok()),
+                                $pos::new(a).zip($neg::new(b)).map(|(a, b)| a.sub(b).get()),
+                                Some(a - b),
                             );
                         }
                         #[test]
-                        fn checked_rem_unsigned(a in 0..=$base::MAX, b in 0..=$uns::MAX) {
-                            assert_eq!(
-                                $pos::new(a).and_then(|a| a.checked_rem_unsigned(b)),
-                                (a as $uns).checked_rem(b).and_then(|n| $pos::try_from(n).ok()),
-                            );
+                        fn neg(n in positive_values()) {
+                            assert_eq!($pos::new(n).map(|n| n.neg().get()), Some(-n));
                         }
                         #[test]
-                        fn checked_pow(a in 0..=$base::MAX, b in 0..u32::MAX) {
+                        fn checked_sub(a in positive_values(), b in positive_values()) {
                             assert_eq!(
-                                $pos::new(a).and_then(|a| a.checked_pow(b)).map(|n| n.get()),
-                                a.checked_pow(b),
+                                $pos::new(a).zip($pos::new(b)).and_then(|(a, b)| a.checked_sub(b)),
+                                $pos::new(a - b),
                             );
                         }
                         #[test]
-                        fn checked_next_power_of_two(n in 0..=$base::MAX) {
+                        fn checked_mul(a in positive_values(), b in positive_values()) {
                             assert_eq!(
-                                $pos::new(n).and_then(|n| n.checked_next_power_of_two()),
-                                (n as $uns).checked_next_power_of_two().and_then(|n| $pos::try_from(n).ok()),
+                                $pos::new(a).zip($pos::new(b)).and_then(|(a, b)| a.checked_mul(b)),
+                                $pos::new(a * b),
                             );
                         }
                         #[test]
-                        fn saturating_sub(a in 0..=$base::MAX, b in 0..=$base::MAX) {
-                            assert_eq!(
-                                $pos::new(a).zip($pos::new(b)).map(|(a, b)| a.saturating_sub(b)).map(|a| a.get()),
-                                Some(a.saturating_sub(b).max(0)),
-                            );
+                        fn try_from_int(n in 0..=i64::MAX, shift in 0..63u32) {
+                            for n in [n, 1 << shift] {
+                                let f = n as $base;
+                                let exact = f as i128 == n as i128;
+                                assert_eq!(
+                                    $pos::try_from(PositiveI64::new(n).unwrap()).ok().map(|n| n.get()),
+                                    exact.then_some(f),
+                                );
+                                assert_eq!(
+                                    PositiveI64::try_from($pos::new(f).unwrap()).ok().map(|n| n.get()),
+                                    (f as i128 <= i64::MAX as i128).then_some(f as i64),
+                                );
+                            }
                         }
                         #[test]
-                        fn saturating_pow(a in 0..=$base::MAX, b in 0..u32::MAX) {
+                        fn try_into_int(n in positive_values()) {
+                            let m = n as i128;
+                            let expected = (m as $base == n && m <= i32::MAX as i128).then_some(m as i32);
                             assert_eq!(
-                                $pos::new(a).map(|a| a.saturating_pow(b)).map(|a| a.get()),
-                                Some((a as $base).saturating_pow(b)),
+                                PositiveI32::try_from($pos::new(n).unwrap()).ok().map(|n| n.get()),
+                                expected,
                             );
                         }
                     }
-                    test_unary_op! { $pos, $base (0..=$base::MAX) :: not }
-                    test_binary! { $pos, $base (0..=$base::MAX, 1..=$base::MAX) :: div, rem }
-                    test_unary! { $pos, $base (0..=$base::MAX)
-                    :: count_zeros, count_ones, leading_zeros, trailing_zeros }
-                    test_unary! { $pos, $uns (0..=$base::MAX) :: is_power_of_two }
-                    test_unary! { $pos, $base (1..=$base::MAX) :: ilog2, ilog10 }
-                    test_unary_checked! { $pos, $base (1..=$base::MAX) :: checked_ilog2, checked_ilog10 }
-                    test_binary_checked! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
-                    :: checked_add, checked_mul, checked_div, checked_rem }
-                    test_binary! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
-                    :: saturating_add, saturating_mul, bitor, bitand, bitxor }
-                    test_assign! { $pos, $base (0..=$base::MAX, 1..=$base::MAX) :: div_assign, rem_assign }
-                    test_assign! { $pos, $base (0..=$base::MAX, 0..=$base::MAX)
-                    :: bitor_assign, bitand_assign, bitxor_assign }
+                    #[test]
+                    fn try_from_int_limits() {
+                        assert_eq!(PositiveI8::try_from($pos::new(127.0).unwrap()), Ok(PositiveI8::MAX));
+                        assert!(PositiveI8::try_from($pos::new(128.0).unwrap()).is_err());
+                        assert!(PositiveI8::try_from($pos::new(0.5).unwrap()).is_err());
+                        assert!(PositiveI64::try_from($pos::new(9223372036854775808.0).unwrap()).is_err());
+                        assert!(PositiveI128::try_from($pos::INFINITY).is_err());
+                        assert_eq!(PositiveI32::try_from($pos::MIN), Ok(PositiveI32::MIN));
+                        assert!($pos::try_from(PositiveI128::MAX).is_err());
+                    }
+                    #[test]
+                    fn zero() {
+                        assert!($pos::new(0.0).is_some());
+                        assert!($pos::new(-0.0).is_none());
+                        assert!($pos::new($base::NAN).is_none());
+                        assert_eq!($pos::INFINITY.checked_mul($pos::MIN), None);
+                        assert_eq!(size_of::<Option<$pos>>(), size_of::<$base>());
+                    }
                 }
                 mod negative {
                     use super::*;
                     proptest! {
                         #[test]
-                        fn valid(n in $base::MIN..0) {
+                        fn valid(n in negative_values()) {
                             assert_eq!($neg::new(n).map(|n| n.get()), Some(n));
                         }
                         #[test]
-                        fn invalid(n in 0..=$base::MAX) {
+                        fn invalid(n in positive_values()) {
                             assert_eq!($neg::new(n).map(|n| n.get()), None);
                         }
                         #[test]
-                        fn checked_abs(n in $base::MIN..0) {
-                            assert_eq!(
-                                $neg::new(n).and_then(|n| n.checked_abs()).map(|n| n.get()),
-                                n.checked_abs(),
-                            );
-                        }
-                        #[test]
-                        fn checked_neg(n in $base::MIN..0) {
-                            assert_eq!(
-                                $neg::new(n).and_then(|n| n.checked_neg()).map(|n| n.get()),
-                                n.checked_neg(),
-                            );
-                        }
-                        #[test]
-                        fn checked_sub(a in $base::MIN..0, b in $base::MIN..0) {
-                            assert_eq!(
-                                $neg::new(a).zip($neg::new(b)).and_then(|(a, b)| a.checked_sub(b)),
-                                a.checked_sub(b).and_then($neg::new),
-                            );
-                        }
-                        #[test]
-                        fn checked_mul_positive(a in $base::MIN..0, b in 0..=$base::MAX) {
+                        fn cmp(a in negative_values(), b in negative_values()) {
                             assert_eq!(
-                                $neg::new(a)
-                                    .zip($pos::new(b))
-                                    .and_then(|(a, b)| a.checked_mul_positive(b)),
-                                a.checked_mul(b).and_then($neg::new),
+                                $neg::new(a).zip($neg::new(b)).map(|(a, b)| a.cmp(&b)),
+                                a.partial_cmp(&b),
                             );
                         }
                         #[test]
-                        fn checked_rem_euclid(a in $base::MIN..0, b in $base::MIN..=$base::MAX) {
+                        fn add(a in negative_values(), b in negative_values()) {
                             assert_eq!(
-                                $neg::new(a).and_then(|a| a.checked_rem_euclid(b)).map(|n| n.get()),
-                                a.checked_rem_euclid(b),
+                                $neg::new(a).zip($neg::new(b)).map(|(a, b)| a.add(b).get()),
+                                Some(a + b),
                             );
                         }
                         #[test]
-                        fn saturating_abs(n in $base::MIN..0) {
-                            assert_eq!(
-                                $neg::new(n).map(|n| n.saturating_abs().get()),
-                                Some(n.saturating_abs()),
-                            );
+                        fn neg(n in negative_values()) {
+                            assert_eq!($neg::new(n).map(|n| n.neg().get()), Some(-n));
                         }
                         #[test]
-                        fn saturating_neg(n in $base::MIN..0) {
+                        fn checked_mul(a in negative_values(), b in negative_values()) {
                             assert_eq!(
-                                $neg::new(n).map(|n| n.saturating_neg().get()),
-                                Some(n.saturating_neg()),
+                                $neg::new(a).zip($neg::new(b)).and_then(|(a, b)| a.checked_mul(b)),
+                                $pos::new(a * b),
                             );
                         }
                         #[test]
-                        fn saturating_sub(a in $base::MIN..0, b in $base::MIN..0) {
-                            assert_eq!(
-                                $neg::new(a)
-                                    .zip($neg::new(b))
-                                    .map(|(a, b)| a.saturating_sub(b).get()),
-                                Some(a.saturating_sub(b).min(-1)),
-                            );
+                        fn try_from_int(n in i64::MIN..0, shift in 0..63u32) {
+                            for n in [n, -1 << shift] {
+                                let f = n as $base;
+                                let exact = f as i128 == n as i128;
+                                assert_eq!(
+                                    $neg::try_from(NegativeI64::new(n).unwrap()).ok().map(|n| n.get()),
+                                    exact.then_some(f),
+                                );
+                                assert_eq!(
+                                    NegativeI64::try_from($neg::new(f).unwrap()).ok().map(|n| n.get()),
+                                    Some(f as i64),
+                                );
+                            }
                         }
                         #[test]
-                        fn saturating_mul_positive(a in $base::MIN..0, b in 0..=$base::MAX) {
+                        fn try_into_int(n in negative_values()) {
+                            let m = n as i128;
+                            let expected = (m as $base == n && m < 0 && m >= i32::MIN as i128).then_some(m as i32);
                             assert_eq!(
-                                $neg::new(a)
-                                    .zip($pos::new(b))
-                                    .map(|(a, b)| a.saturating_mul_positive(b).get()),
-                                Some(a.saturating_mul(b).min(-1)),
+                                NegativeI32::try_from($neg::new(n).unwrap()).ok().map(|n| n.get()),
+                                expected,
                             );
                         }
-                        #[test]
-                        fn bitxor_assign(a in $base::MIN..0, b in 0..=$base::MAX) {
-                            let mut a1 = $neg::new(a);
-                            let mut a2 = a;
-                            if let Some((a, b)) = a1.as_mut().zip($pos::new(b)) {
-                                a.bitxor_assign(b);
-                            }
-                            a2.bitxor_assign(b);
-                            assert_eq!(a1.map(|a| a.get()), Some(a2));
-                        }
                     }
-                    test_unary_op! { $neg, $base ($base::MIN..0) :: not }
-                    test_unary! { $neg, $base ($base::MIN..0)
-                    :: count_zeros, count_ones, leading_zeros, trailing_zeros }
-                    test_binary_checked! { $neg, $base ($base::MIN..0, $base::MIN..0)
-                    :: checked_add, checked_mul, checked_div, checked_div_euclid }
-                    test_binary! { $neg, $base ($base::MIN..0, $base::MIN..0)
-                    :: saturating_add, saturating_mul, bitor, bitand, bitxor }
-                    test_assign! { $neg, $base ($base::MIN..0, $base::MIN..0)
-                    :: bitor_assign, bitand_assign }
+                    #[test]
+                    fn try_from_int_limits() {
+                        assert_eq!(NegativeI8::try_from($neg::new(-128.0).unwrap()), Ok(NegativeI8::MIN));
+                        assert!(NegativeI8::try_from($neg::new(-129.0).unwrap()).is_err());
+                        assert!(NegativeI8::try_from($neg::new(-0.0).unwrap()).is_err());
+                        assert!(NegativeI8::try_from($neg::new(-0.5).unwrap()).is_err());
+                        assert!(NegativeI128::try_from($neg::new($base::NEG_INFINITY).unwrap()).is_err());
+                        assert_eq!($neg::try_from(NegativeI128::MIN).map(|n| n.get()), Ok(i128::MIN as $base));
+                    }
+                    #[test]
+                    fn zero() {
+                        assert!($neg::new(-0.0).is_some());
+                        assert!($neg::new(0.0).is_none());
+                        assert!($neg::new(-$base::NAN).is_none());
+                        assert_eq!(size_of::<Option<$neg>>(), size_of::<$base>());
+                    }
                 }
             }
         };
     }
-    test_type! { i8, u8, PositiveI8, NegativeI8 }
-    test_type! { i16, u16, PositiveI16, NegativeI16 }
-    test_type! { i32, u32, PositiveI32, NegativeI32 }
-    test_type! { i64, u64, PositiveI64, NegativeI64 }
-    test_type! { isize, usize, PositiveIsize, NegativeIsize }
+    test_float! { f32, PositiveF32, NegativeF32 }
+    test_float! { f64, PositiveF64, NegativeF64 }
+    #[test]
+    fn float_narrowing() {
+        assert_eq!(PositiveF32::try_from(0.5f64).map(|n| n.get()), Ok(0.5));
+        assert_eq!(PositiveF32::try_from(f64::INFINITY), Ok(PositiveF32::INFINITY));
+        assert!(PositiveF32::try_from(0.1f64).is_err());
+        assert!(PositiveF32::try_from(f64::MAX).is_err());
+        assert!(PositiveF32::try_from(f64::NAN).is_err());
+        assert!(PositiveF32::try_from(-1.0f64).is_err());
+        assert_eq!(PositiveF32::try_from(PositiveF64::new(2.0).unwrap()).map(|n| n.get()), Ok(2.0));
+        assert!(PositiveF32::try_from(PositiveF64::MAX).is_err());
+        assert_eq!(NegativeF32::try_from(-0.0f64).map(|n| n.get().to_bits()), Ok((-0.0f32).to_bits()));
+        assert!(NegativeF32::try_from(0.0f64).is_err());
+        assert!(NegativeF32::try_from(NegativeF64::new(-0.1).unwrap()).is_err());
+    }
 }